@@ -0,0 +1,529 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response, StdError,
+    StdResult, SubMsg, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+use cw_utils::parse_reply_instantiate_data;
+use hub::{msg::ExecuteMsg as HubExecuteMsg, state::MetadataField};
+use migrations::parse_version;
+use seat::msg::FeeConfigMsg;
+use seat::payments::FeeConfig;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, HubsResponse, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{Config, HubRecord, PendingHub, CONFIG, HUBS, PENDING_HUB};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:factory";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const INSTANTIATE_HUB_REPLY_ID: u64 = 1;
+pub const INSTANTIATE_SEAT_REPLY_ID: u64 = 2;
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    validate_fee(deps.as_ref(), &msg.fee)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            owner: info.sender,
+            hub_code_id: msg.hub_code_id,
+            seat_code_id: msg.seat_code_id,
+            fee: msg.fee,
+        },
+    )?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new().add_attribute("action", "instantiate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::CreateHub { hub, seat, label } => create_hub(deps, env, hub, seat, label),
+        ExecuteMsg::UpdateConfig {
+            owner,
+            hub_code_id,
+            seat_code_id,
+            fee,
+        } => update_config(deps, info, owner, hub_code_id, seat_code_id, fee),
+    }
+}
+
+fn create_hub(
+    deps: DepsMut,
+    env: Env,
+    mut hub: hub::msg::InstantiateMsg,
+    mut seat: seat::msg::InstantiateMsg,
+    label: String,
+) -> Result<Response, ContractError> {
+    if PENDING_HUB.exists(deps.storage) {
+        return Err(ContractError::HubCreationInProgress {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let owner = deps.api.addr_validate(&hub.ownable.owner)?;
+    // the factory owns the hub until the seat contract is linked
+    hub.ownable.owner = env.contract.address.to_string();
+    // a new collection has no tokens to list
+    seat.sellable = None;
+    // the platform, not the creator, sets the fee and administers it
+    seat.fee = Some(config.fee);
+
+    let instantiate_hub = WasmMsg::Instantiate {
+        admin: Some(owner.to_string()),
+        code_id: config.hub_code_id,
+        msg: to_binary(&hub)?,
+        funds: vec![],
+        label: format!("{} hub", label),
+    };
+    PENDING_HUB.save(
+        deps.storage,
+        &PendingHub {
+            owner: owner.clone(),
+            label,
+            seat,
+            hub: None,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_hub")
+        .add_attribute("owner", owner)
+        .add_submessage(SubMsg::reply_on_success(
+            instantiate_hub,
+            INSTANTIATE_HUB_REPLY_ID,
+        )))
+}
+
+fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    owner: Option<String>,
+    hub_code_id: Option<u64>,
+    seat_code_id: Option<u64>,
+    fee: Option<FeeConfigMsg>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    if let Some(owner) = owner {
+        config.owner = deps.api.addr_validate(&owner)?;
+    }
+    if let Some(hub_code_id) = hub_code_id {
+        config.hub_code_id = hub_code_id;
+    }
+    if let Some(seat_code_id) = seat_code_id {
+        config.seat_code_id = seat_code_id;
+    }
+    if let Some(fee) = fee {
+        validate_fee(deps.as_ref(), &fee)?;
+        config.fee = fee;
+    }
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+/// Errors unless seat collections would accept `fee`
+fn validate_fee(deps: Deps, fee: &FeeConfigMsg) -> Result<(), ContractError> {
+    FeeConfig::new(deps.api, fee.clone())?;
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        INSTANTIATE_HUB_REPLY_ID => hub_instantiated(deps, msg),
+        INSTANTIATE_SEAT_REPLY_ID => seat_instantiated(deps, env, msg),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+fn hub_instantiated(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let res = parse_reply_instantiate_data(msg)?;
+    let hub = deps.api.addr_validate(&res.contract_address)?;
+    let config = CONFIG.load(deps.storage)?;
+    let mut pending = PENDING_HUB.load(deps.storage)?;
+    pending.seat.hub_contract = hub.to_string();
+
+    let instantiate_seat = WasmMsg::Instantiate {
+        admin: Some(pending.owner.to_string()),
+        code_id: config.seat_code_id,
+        msg: to_binary(&pending.seat)?,
+        funds: vec![],
+        label: format!("{} seats", pending.label),
+    };
+    pending.hub = Some(hub.clone());
+    PENDING_HUB.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("hub_contract", hub)
+        .add_submessage(SubMsg::reply_on_success(
+            instantiate_seat,
+            INSTANTIATE_SEAT_REPLY_ID,
+        )))
+}
+
+fn seat_instantiated(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let res = parse_reply_instantiate_data(msg)?;
+    let seat = deps.api.addr_validate(&res.contract_address)?;
+    let pending = PENDING_HUB.load(deps.storage)?;
+    PENDING_HUB.remove(deps.storage);
+    let hub = pending
+        .hub
+        .ok_or_else(|| StdError::not_found("hub contract"))?;
+
+    HUBS.save(
+        deps.storage,
+        &hub,
+        &HubRecord {
+            hub: hub.clone(),
+            seat: seat.clone(),
+            owner: pending.owner.clone(),
+            created_at: env.block.time,
+        },
+    )?;
+
+    // link the seat contract, then hand the hub over to its owner
+    let link_seat = WasmMsg::Execute {
+        contract_addr: hub.to_string(),
+        msg: to_binary(&HubExecuteMsg::UpdateMetadata(MetadataField::SeatContract(
+            seat.to_string(),
+        )))?,
+        funds: vec![],
+    };
+    let transfer_ownership = WasmMsg::Execute {
+        contract_addr: hub.to_string(),
+        msg: to_binary(&HubExecuteMsg::Ownable(ownable::ExecuteMsg::SetOwner(
+            pending.owner.to_string(),
+        )))?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_attribute("seat_contract", seat)
+        .add_attribute("owner", pending.owner)
+        .add_message(link_seat)
+        .add_message(transfer_ownership))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Hub { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            to_binary(&HUBS.load(deps.storage, &address)?)
+        }
+        QueryMsg::Hubs { start_after, limit } => to_binary(&query_hubs(deps, start_after, limit)?),
+    }
+}
+
+fn query_hubs(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<HubsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+    let hubs = HUBS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, record)| record))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(HubsResponse { hubs })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver = cw2::get_contract_version(deps.storage)?;
+    // ensure we are migrating from an allowed contract
+    if ver.contract != CONTRACT_NAME {
+        return Err(StdError::generic_err("Can only upgrade from same type").into());
+    }
+    let old_contract_ver = parse_version(&ver.version)?;
+    let new_contract_ver = parse_version(CONTRACT_VERSION)?;
+    // ensure we are migrating from an allowed version
+    if old_contract_ver.ge(&new_contract_ver) {
+        return Err(StdError::generic_err("Cannot upgrade from a newer version").into());
+    }
+    //set the new version
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{
+        from_binary,
+        testing::{mock_dependencies, mock_env, mock_info, MOCK_CONTRACT_ADDR},
+        Addr, CosmosMsg, SubMsgResponse, SubMsgResult,
+    };
+    use serde_json::{from_str, json};
+
+    const CREATOR: &str = "creator";
+    const HUB_OWNER: &str = "hub_owner";
+
+    // protobuf encoded MsgInstantiateContractResponse
+    fn instantiate_reply(id: u64, contract_address: &str) -> Reply {
+        let mut data = vec![0x0a, contract_address.len() as u8];
+        data.extend_from_slice(contract_address.as_bytes());
+        Reply {
+            id,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(Binary::from(data)),
+            }),
+        }
+    }
+
+    fn platform_fee() -> FeeConfigMsg {
+        FeeConfigMsg {
+            admin: "platform".to_string(),
+            fee_bps: 250,
+            collector: "collector".to_string(),
+        }
+    }
+
+    fn create_hub_msg() -> ExecuteMsg {
+        let hub = json!({
+            "ownable": {"owner": HUB_OWNER},
+            "metadata": {"metadata": {
+                "name": "Kenny's contract",
+                "hub_url": "find me here",
+                "description": "Awesome Hub",
+                "tags": ["awesome"],
+                "social_links": [],
+                "creator": HUB_OWNER,
+                "thumbnail_image_url": "image link here",
+                "banner_image_url": "image link here",
+                "seat_contract": null
+            }}
+        });
+        let seat = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract",
+                "symbol": "KNY",
+                "minter": HUB_OWNER,
+            },
+            "metadata": {"metadata": {
+                "name": "Kenny's seats",
+                "image_uri": "image",
+                "description": "description",
+                "benefits": [],
+                "template_number": 1,
+                "image_settings": {"seat_name": true, "hub_name": true}
+            }},
+            "ownable": {"owner": HUB_OWNER},
+            "redeemable": {"locked_items": []},
+            "sellable": {"tokens": {}},
+            "sales": {},
+            "hub_contract": "",
+            "payment_denoms": ["uturnt"],
+            "fee": {"admin": HUB_OWNER, "fee_bps": 0, "collector": HUB_OWNER}
+        });
+        from_str(&json!({"create_hub": {"hub": hub, "seat": seat, "label": "kenny"}}).to_string())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_create_hub() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg {
+                hub_code_id: 1,
+                seat_code_id: 2,
+                fee: platform_fee(),
+            },
+        )
+        .unwrap();
+
+        // the hub is instantiated first, owned by the factory
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), create_hub_msg()).unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].id, INSTANTIATE_HUB_REPLY_ID);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Instantiate { code_id, msg, .. }) => {
+                assert_eq!(*code_id, 1);
+                let hub_msg: hub::msg::InstantiateMsg = from_binary(msg).unwrap();
+                assert_eq!(hub_msg.ownable.owner, MOCK_CONTRACT_ADDR);
+            }
+            _ => unreachable!(),
+        }
+
+        // only one hub can be created at a time
+        execute(deps.as_mut(), env.clone(), info, create_hub_msg())
+            .expect_err("hub creation in progress");
+
+        // the seat collection is instantiated for the new hub, with the platform
+        // fee rather than the creator's
+        let res = reply(
+            deps.as_mut(),
+            env.clone(),
+            instantiate_reply(INSTANTIATE_HUB_REPLY_ID, "hub_contract"),
+        )
+        .unwrap();
+        assert_eq!(res.messages[0].id, INSTANTIATE_SEAT_REPLY_ID);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Instantiate { code_id, msg, .. }) => {
+                assert_eq!(*code_id, 2);
+                let seat_msg: seat::msg::InstantiateMsg = from_binary(msg).unwrap();
+                assert_eq!(seat_msg.hub_contract, "hub_contract");
+                assert_eq!(seat_msg.fee, Some(platform_fee()));
+            }
+            _ => unreachable!(),
+        }
+
+        // the seat contract is linked and the hub handed over to its owner
+        let res = reply(
+            deps.as_mut(),
+            env.clone(),
+            instantiate_reply(INSTANTIATE_SEAT_REPLY_ID, "seat_contract"),
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 2);
+        assert!(!PENDING_HUB.exists(deps.as_ref().storage));
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Hubs {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let hubs: HubsResponse = from_binary(&res).unwrap();
+        assert_eq!(hubs.hubs.len(), 1);
+        assert_eq!(hubs.hubs[0].hub, Addr::unchecked("hub_contract"));
+        assert_eq!(hubs.hubs[0].seat, Addr::unchecked("seat_contract"));
+        assert_eq!(hubs.hubs[0].owner, Addr::unchecked(HUB_OWNER));
+
+        let res = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::Hubs {
+                start_after: Some("hub_contract".to_string()),
+                limit: None,
+            },
+        )
+        .unwrap();
+        let hubs: HubsResponse = from_binary(&res).unwrap();
+        assert!(hubs.hubs.is_empty());
+    }
+
+    #[test]
+    fn test_update_config() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            InstantiateMsg {
+                hub_code_id: 1,
+                seat_code_id: 2,
+                fee: platform_fee(),
+            },
+        )
+        .unwrap();
+
+        let update = || ExecuteMsg::UpdateConfig {
+            owner: None,
+            hub_code_id: Some(3),
+            seat_code_id: None,
+            fee: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("hacker", &[]),
+            update(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            update(),
+        )
+        .unwrap();
+
+        // the platform fee stays within what seat collections accept
+        let update_fee = |fee_bps: u16| ExecuteMsg::UpdateConfig {
+            owner: None,
+            hub_code_id: None,
+            seat_code_id: None,
+            fee: Some(FeeConfigMsg {
+                fee_bps,
+                ..platform_fee()
+            }),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            update_fee(1001),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Seat(_)));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            update_fee(500),
+        )
+        .unwrap();
+
+        let res = query(deps.as_ref(), env, QueryMsg::Config {}).unwrap();
+        let config: Config = from_binary(&res).unwrap();
+        assert_eq!(config.hub_code_id, 3);
+        assert_eq!(config.seat_code_id, 2);
+        assert_eq!(config.fee.fee_bps, 500);
+    }
+
+    #[test]
+    fn test_migrate() {
+        let mut deps = mock_dependencies();
+
+        // an unparsable version is an error, not a panic
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "latest").unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Std(StdError::GenericErr { .. })
+        ));
+
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.0.1").unwrap();
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        let version = cw2::get_contract_version(deps.as_ref().storage).unwrap();
+        assert_eq!(version.version, CONTRACT_VERSION);
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    }
+}
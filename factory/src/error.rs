@@ -0,0 +1,24 @@
+use cosmwasm_std::StdError;
+use cw_utils::ParseReplyError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    ParseReply(#[from] ParseReplyError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("A hub is already being created")]
+    HubCreationInProgress {},
+
+    #[error("Unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("{0}")]
+    Seat(#[from] seat::ContractError),
+}
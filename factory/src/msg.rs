@@ -0,0 +1,54 @@
+use cosmwasm_schema::cw_serde;
+use seat::msg::FeeConfigMsg;
+use serde::{Deserialize, Serialize};
+
+use crate::state::HubRecord;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub hub_code_id: u64,
+    pub seat_code_id: u64,
+    /// Platform fee of every seat collection created
+    pub fee: FeeConfigMsg,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Instantiates a hub and its seat collection and links them together.
+    /// The seat `hub_contract` is filled in by the factory, and ownership of
+    /// the hub is handed to `hub.ownable.owner` once the seat contract is set.
+    /// The seat `fee` is replaced by the platform fee.
+    CreateHub {
+        hub: hub::msg::InstantiateMsg,
+        seat: seat::msg::InstantiateMsg,
+        label: String,
+    },
+    UpdateConfig {
+        owner: Option<String>,
+        hub_code_id: Option<u64>,
+        seat_code_id: Option<u64>,
+        /// Platform fee of the seat collections created from now on
+        fee: Option<FeeConfigMsg>,
+    },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum QueryMsg {
+    Config {},
+    Hub {
+        address: String,
+    },
+    Hubs {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct HubsResponse {
+    pub hubs: Vec<HubRecord>,
+}
@@ -0,0 +1,35 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp};
+use cw_storage_plus::{Item, Map};
+use seat::msg::FeeConfigMsg;
+use serde::{Deserialize, Serialize};
+
+#[cw_serde]
+pub struct Config {
+    pub owner: Addr,
+    pub hub_code_id: u64,
+    pub seat_code_id: u64,
+    /// Platform fee given to every seat collection created
+    pub fee: FeeConfigMsg,
+}
+
+#[cw_serde]
+pub struct HubRecord {
+    pub hub: Addr,
+    pub seat: Addr,
+    pub owner: Addr,
+    pub created_at: Timestamp,
+}
+
+/// A hub creation in flight between the instantiate replies
+#[derive(Serialize, Deserialize)]
+pub struct PendingHub {
+    pub owner: Addr,
+    pub label: String,
+    pub seat: seat::msg::InstantiateMsg,
+    pub hub: Option<Addr>,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PENDING_HUB: Item<PendingHub> = Item::new("pending_hub");
+pub const HUBS: Map<&Addr, HubRecord> = Map::new("hubs");
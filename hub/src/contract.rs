@@ -1,16 +1,17 @@
 #[cfg(not(feature = "library"))]
-use cosmwasm_std::{entry_point, from_slice, to_vec};
+use cosmwasm_std::entry_point;
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
 use cw2::set_contract_version;
-use semver::Version;
+use migrations::parse_version;
 
 use crate::error::ContractError;
+use crate::migrate::MIGRATIONS;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
-use crate::state::{Config, HubModules};
+use crate::state::HubModules;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:hub";
-const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -45,48 +46,61 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
-    // validate new owner address
-    deps.api.addr_validate(&msg.owner)?;
+pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     let ver = cw2::get_contract_version(deps.storage)?;
     // ensure we are migrating from an allowed contract
     if ver.contract != CONTRACT_NAME {
         return Err(StdError::generic_err("Can only upgrade from same type").into());
     }
-    let old_contract_ver = Version::parse(&ver.version).unwrap();
-    let new_contract_ver = Version::parse(CONTRACT_VERSION).unwrap();
+    let old_contract_ver = parse_version(&ver.version)?;
+    let new_contract_ver = parse_version(CONTRACT_VERSION)?;
     // ensure we are migrating from an allowed version
     if old_contract_ver.ge(&new_contract_ver) {
         return Err(StdError::generic_err("Cannot upgrade from a newer version").into());
     }
 
-    let data = deps
-        .storage
-        .get(b"config")
-        .ok_or_else(|| StdError::not_found("State"))?;
-    let mut config: Config = from_slice(&data)?;
-    config.owner = deps.api.addr_validate(&msg.owner)?;
-    deps.storage.set(b"config", &to_vec(&config)?);
+    let applied = migrations::migrate(
+        deps.branch(),
+        &env,
+        MIGRATIONS,
+        &old_contract_ver,
+        &new_contract_ver,
+        msg.owner.as_deref(),
+    )?;
+    let applied: Vec<&str> = applied.iter().map(|step| step.to).collect();
+    let mut res = Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION)
+        .add_attribute("migrations", applied.join(","));
+
+    if let Some(owner) = msg.owner {
+        res = res.add_attribute("owner", owner);
+    }
     //set the new version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    Ok(Response::default())
+    Ok(res)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::state::{HubMetadata, SocialLinks};
+    use crate::msg::{IsMemberResponse, MembersResponse};
+    use crate::state::{HubMetadata, MetadataField, SeatContract, SocialLinks, SEAT_CONTRACTS};
 
     use super::*;
     use cosmwasm_std::{
         from_binary,
-        testing::{mock_dependencies, mock_env, mock_info},
+        testing::{mock_dependencies, mock_env, mock_info, MOCK_CONTRACT_ADDR},
+        to_binary, Addr, ContractResult, SystemError, SystemResult, WasmQuery,
     };
     use metadata::QueryResp as MetadataQueryResp;
+    use migrations::{MigrationReport, NamespaceChanges};
     use ownable::QueryResp as OwnableQueryResp;
-    use serde_json::{from_str, json};
+    use serde_json::{from_str, json, Value};
 
     const CREATOR: &str = "CREATOR";
+    const SEAT_CONTRACT: &str = "seat_contract";
     // make sure ownable module is instantiated
     #[test]
     fn test_ownable_module() {
@@ -204,22 +218,552 @@ mod tests {
         let res = instantiate(deps.as_mut(), env.clone(), info.clone(), instantiate_msg).unwrap();
         assert_eq!(0, res.messages.len());
 
-        msg = json!({"update_metadata": { "seat_contract": env.contract.address.to_string() }})
-            .to_string();
+        // seat contracts report which hub they were instantiated for
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, .. } => {
+                let hub = if contract_addr == SEAT_CONTRACT {
+                    MOCK_CONTRACT_ADDR
+                } else {
+                    "other_hub"
+                };
+                SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&Addr::unchecked(hub)).unwrap(),
+                ))
+            }
+            _ => SystemResult::Err(SystemError::Unknown {}),
+        });
+
+        // a seat contract belonging to another hub is rejected
+        msg = json!({"update_metadata": { "seat_contract": "malicious_seat" }}).to_string();
+        let exec_msg: ExecuteMsg = from_str(&msg).unwrap();
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), exec_msg).unwrap_err();
+        assert!(matches!(err, ContractError::SeatContractMismatch { .. }));
+
+        msg = json!({"update_metadata": { "seat_contract": SEAT_CONTRACT }}).to_string();
         let exec_msg: ExecuteMsg = from_str(&msg).unwrap();
         execute(deps.as_mut(), env.clone(), info, exec_msg).expect("seat contract set");
 
         msg = json!({"metadata": {"get_metadata": {}}}).to_string();
         let query_msg: QueryMsg = from_str(&msg).unwrap();
-        let res = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+        let res = query(deps.as_ref(), env, query_msg).unwrap();
+        let metadata: MetadataQueryResp<HubMetadata> = from_binary(&res).unwrap();
+        match metadata {
+            MetadataQueryResp::Metadata(meta) => {
+                assert_eq!(meta.seat_contract.unwrap().to_string(), SEAT_CONTRACT);
+            }
+        }
+    }
+
+    #[test]
+    fn test_updating_metadata_fields() {
+        let mut deps = mock_dependencies();
+        let metadata_msg = HubMetadata {
+            name: "Kenny's contract".to_string(),
+            hub_url: "find me here".to_string(),
+            description: "Awesome Hub".to_string(),
+            tags: vec!["awesome".to_string(), "wild".to_string()],
+            social_links: vec![SocialLinks {
+                name: "discord".to_string(),
+                url: "discord link here".to_string(),
+            }],
+            creator: CREATOR.to_string(),
+            thumbnail_image_url: "image link here".to_string(),
+            banner_image_url: "image link here".to_string(),
+            seat_contract: None,
+        };
+        let msg = json!({
+            "metadata": {"metadata": metadata_msg},
+            "ownable": {"owner": CREATOR}
+        })
+        .to_string();
+        let instantiate_msg: InstantiateMsg = from_str(&msg).unwrap();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+
+        instantiate(deps.as_mut(), env.clone(), info.clone(), instantiate_msg).unwrap();
+
+        // only the owner can update the metadata
+        let exec_msg = ExecuteMsg::UpdateMetadata(MetadataField::Name("New name".to_string()));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("hacker", &[]),
+            exec_msg,
+        )
+        .expect_err("non owner cannot update metadata");
+
+        let exec_msg = ExecuteMsg::UpdateMetadata(MetadataField::Name("New name".to_string()));
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), exec_msg).unwrap();
+        assert_eq!(res.attributes[1].value, "name");
+        assert_eq!(res.attributes[2].value, "New name");
+
+        for field in [
+            MetadataField::AddTag("new".to_string()),
+            MetadataField::RemoveTag("wild".to_string()),
+            MetadataField::AddSocialLink(SocialLinks {
+                name: "twitter".to_string(),
+                url: "twitter link here".to_string(),
+            }),
+            MetadataField::RemoveSocialLink("discord".to_string()),
+            MetadataField::BannerImageUrl("new banner".to_string()),
+        ] {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::UpdateMetadata(field),
+            )
+            .expect("metadata field updated");
+        }
+
+        // invalid updates are rejected
+        for field in [
+            MetadataField::Name("".to_string()),
+            MetadataField::AddTag("awesome".to_string()),
+            MetadataField::RemoveTag("missing".to_string()),
+            MetadataField::RemoveSocialLink("discord".to_string()),
+        ] {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::UpdateMetadata(field),
+            )
+            .unwrap_err();
+            assert!(matches!(err, ContractError::InvalidMetadataField { .. }));
+        }
+
+        let query_msg: QueryMsg =
+            from_str(&json!({"metadata": {"get_metadata": {}}}).to_string()).unwrap();
+        let res = query(deps.as_ref(), env, query_msg).unwrap();
+        let metadata: MetadataQueryResp<HubMetadata> = from_binary(&res).unwrap();
+        match metadata {
+            MetadataQueryResp::Metadata(meta) => {
+                assert_eq!(meta.name, "New name");
+                assert_eq!(meta.tags, vec!["awesome".to_string(), "new".to_string()]);
+                assert_eq!(meta.social_links.len(), 1);
+                assert_eq!(meta.social_links[0].name, "twitter");
+                assert_eq!(meta.banner_image_url, "new banner");
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiple_seat_contracts() {
+        let mut deps = mock_dependencies();
+        let metadata_msg = HubMetadata {
+            name: "Kenny's contract".to_string(),
+            hub_url: "find me here".to_string(),
+            description: "Awesome Hub".to_string(),
+            tags: vec!["awesome".to_string(), "wild".to_string()],
+            social_links: vec![SocialLinks {
+                name: "discord".to_string(),
+                url: "discord link here".to_string(),
+            }],
+            creator: CREATOR.to_string(),
+            thumbnail_image_url: "image link here".to_string(),
+            banner_image_url: "image link here".to_string(),
+            seat_contract: None,
+        };
+        let msg = json!({
+            "metadata": {"metadata": metadata_msg},
+            "ownable": {"owner": CREATOR}
+        })
+        .to_string();
+        let instantiate_msg: InstantiateMsg = from_str(&msg).unwrap();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(deps.as_mut(), env.clone(), info.clone(), instantiate_msg).unwrap();
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { .. } => SystemResult::Ok(ContractResult::Ok(
+                to_binary(&Addr::unchecked(MOCK_CONTRACT_ADDR)).unwrap(),
+            )),
+            _ => SystemResult::Err(SystemError::Unknown {}),
+        });
+
+        for (name, address) in [
+            ("founders", "founders_seat"),
+            ("supporters", "supporters_seat"),
+        ] {
+            let exec_msg = ExecuteMsg::AddSeatContract {
+                name: name.to_string(),
+                address: address.to_string(),
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), exec_msg).unwrap();
+        }
+        let exec_msg = ExecuteMsg::AddSeatContract {
+            name: "founders".to_string(),
+            address: "other_seat".to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), exec_msg)
+            .expect_err("seat contract names are unique");
+        let exec_msg = ExecuteMsg::RemoveSeatContract {
+            name: "founders".to_string(),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("hacker", &[]),
+            exec_msg,
+        )
+        .expect_err("only the owner manages seat contracts");
+
+        let exec_msg = ExecuteMsg::ReorderSeatContracts {
+            names: vec!["supporters".to_string()],
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), exec_msg)
+            .expect_err("every seat contract must be ordered");
+        let exec_msg = ExecuteMsg::ReorderSeatContracts {
+            names: vec!["supporters".to_string(), "founders".to_string()],
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), exec_msg).unwrap();
+
+        // named collections are not repointed through the single seat contract field
+        let exec_msg =
+            ExecuteMsg::UpdateMetadata(MetadataField::SeatContract("other_seat".to_string()));
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), exec_msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidMetadataField { .. }));
+
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::SeatContracts {}).unwrap();
+        let seats: Vec<SeatContract> = from_binary(&res).unwrap();
+        assert_eq!(seats.len(), 2);
+        assert_eq!(seats[0].name, "supporters");
+        assert_eq!(seats[1].name, "founders");
+
+        // the primary seat collection is mirrored in the metadata
+        let query_msg = QueryMsg::Metadata(metadata::QueryMsg::GetMetadata {});
+        let res = query(deps.as_ref(), env.clone(), query_msg.clone()).unwrap();
+        let metadata: MetadataQueryResp<HubMetadata> = from_binary(&res).unwrap();
+        match metadata {
+            MetadataQueryResp::Metadata(meta) => {
+                assert_eq!(meta.seat_contract.unwrap(), "supporters_seat");
+            }
+        }
+
+        let exec_msg = ExecuteMsg::RemoveSeatContract {
+            name: "supporters".to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), info, exec_msg).unwrap();
+        let res = query(deps.as_ref(), env, query_msg).unwrap();
         let metadata: MetadataQueryResp<HubMetadata> = from_binary(&res).unwrap();
         match metadata {
             MetadataQueryResp::Metadata(meta) => {
-                assert_eq!(
-                    meta.seat_contract.unwrap().to_string(),
-                    env.contract.address.to_string()
-                );
+                assert_eq!(meta.seat_contract.unwrap(), "founders_seat");
+            }
+        }
+    }
+
+    #[test]
+    fn test_membership() {
+        let mut deps = mock_dependencies();
+        let metadata_msg = HubMetadata {
+            name: "Kenny's contract".to_string(),
+            hub_url: "find me here".to_string(),
+            description: "Awesome Hub".to_string(),
+            tags: vec!["awesome".to_string(), "wild".to_string()],
+            social_links: vec![SocialLinks {
+                name: "discord".to_string(),
+                url: "discord link here".to_string(),
+            }],
+            creator: CREATOR.to_string(),
+            thumbnail_image_url: "image link here".to_string(),
+            banner_image_url: "image link here".to_string(),
+            seat_contract: None,
+        };
+        let msg = json!({
+            "metadata": {"metadata": metadata_msg},
+            "ownable": {"owner": CREATOR}
+        })
+        .to_string();
+        let instantiate_msg: InstantiateMsg = from_str(&msg).unwrap();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(deps.as_mut(), env.clone(), info.clone(), instantiate_msg).unwrap();
+
+        // "alice" holds seats 1 and 2, "bob" holds seat 3 and "dave" holds the
+        // expired seat 4, served two seats per page
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { msg, .. } => {
+                let msg: Value = serde_json::from_slice(msg.as_slice()).unwrap();
+                let res = if msg.get("hub_contract").is_some() {
+                    to_binary(&Addr::unchecked(MOCK_CONTRACT_ADDR))
+                } else {
+                    let query = &msg["all_seats"];
+                    let limit = query["limit"].as_u64().unwrap_or(2).min(2) as usize;
+                    let seats: Vec<Value> = [
+                        ("1", "alice", true),
+                        ("2", "alice", true),
+                        ("3", "bob", true),
+                        ("4", "dave", false),
+                    ]
+                    .into_iter()
+                    .filter(|(token_id, owner, _)| {
+                        let after_start = match query["start_after"].as_str() {
+                            Some(start_after) => *token_id > start_after,
+                            None => true,
+                        };
+                        let owned = match query["owner"].as_str() {
+                            Some(filter) => *owner == filter,
+                            None => true,
+                        };
+                        after_start && owned
+                    })
+                    .map(|(token_id, owner, active)| {
+                        json!({"token_id": token_id, "owner": owner, "active": active})
+                    })
+                    .collect();
+                    let next_start_after = match seats.len() > limit {
+                        true => seats[limit - 1]["token_id"].as_str().map(str::to_string),
+                        false => None,
+                    };
+                    let seats: Vec<Value> = seats.into_iter().take(limit).collect();
+                    to_binary(&json!({"seats": seats, "next_start_after": next_start_after}))
+                };
+                SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+            }
+            _ => SystemResult::Err(SystemError::Unknown {}),
+        });
+
+        let exec_msg =
+            ExecuteMsg::UpdateMetadata(MetadataField::SeatContract(SEAT_CONTRACT.to_string()));
+        execute(deps.as_mut(), env.clone(), info, exec_msg).unwrap();
+
+        let query_msg = QueryMsg::IsMember {
+            address: "alice".to_string(),
+        };
+        let res = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+        let member: IsMemberResponse = from_binary(&res).unwrap();
+        assert!(member.is_member);
+        assert_eq!(member.seats[0].seat_contract, SEAT_CONTRACT);
+        assert_eq!(member.seats[0].token_ids, vec!["1", "2"]);
+
+        let query_msg = QueryMsg::IsMember {
+            address: "carol".to_string(),
+        };
+        let res = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+        let member: IsMemberResponse = from_binary(&res).unwrap();
+        assert!(!member.is_member);
+
+        // an expired seat does not make its owner a member
+        let query_msg = QueryMsg::IsMember {
+            address: "dave".to_string(),
+        };
+        let res = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+        let member: IsMemberResponse = from_binary(&res).unwrap();
+        assert!(!member.is_member);
+
+        let query_msg = QueryMsg::Members {
+            start_after: None,
+            limit: Some(1),
+        };
+        let res = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+        let members: MembersResponse = from_binary(&res).unwrap();
+        assert_eq!(members.members.len(), 1);
+        assert_eq!(members.members[0].address, "alice");
+        assert_eq!(members.members[0].seats[0].token_ids, vec!["1"]);
+        let cursor = members.next_start_after.unwrap();
+        assert_eq!(cursor.collection, "default");
+        assert_eq!(cursor.token_id, "1");
+
+        // pages follow the seat contract's own pagination
+        let query_msg = QueryMsg::Members {
+            start_after: Some(cursor),
+            limit: None,
+        };
+        let res = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+        let members: MembersResponse = from_binary(&res).unwrap();
+        assert_eq!(members.members.len(), 2);
+        assert_eq!(members.members[0].address, "alice");
+        assert_eq!(members.members[0].seats[0].token_ids, vec!["2"]);
+        assert_eq!(members.members[1].address, "bob");
+        assert_eq!(members.members[1].seats[0].token_ids, vec!["3"]);
+        let cursor = members.next_start_after.unwrap();
+        assert_eq!(cursor.token_id, "3");
+
+        // the expired seat is skipped and the last page has no cursor
+        let query_msg = QueryMsg::Members {
+            start_after: Some(cursor),
+            limit: None,
+        };
+        let res = query(deps.as_ref(), env, query_msg).unwrap();
+        let members: MembersResponse = from_binary(&res).unwrap();
+        assert!(members.members.is_empty());
+        assert!(members.next_start_after.is_none());
+    }
+
+    #[test]
+    fn test_module_query_errors() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+
+        // metadata was never instantiated, the query fails instead of panicking
+        let msg = json!({"metadata": {"get_metadata": {}}}).to_string();
+        let query_msg: QueryMsg = from_str(&msg).unwrap();
+        let err = query(deps.as_ref(), env, query_msg).unwrap_err();
+        match err {
+            StdError::GenericErr { msg } => assert!(msg.starts_with("metadata query failed")),
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn test_migrate() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = HubMetadata {
+            name: "Kenny's contract".to_string(),
+            hub_url: "find me here".to_string(),
+            description: "Awesome Hub".to_string(),
+            tags: vec![],
+            social_links: vec![],
+            creator: CREATOR.to_string(),
+            thumbnail_image_url: "image link here".to_string(),
+            banner_image_url: "image link here".to_string(),
+            seat_contract: Some(Addr::unchecked(SEAT_CONTRACT)),
+        };
+        let msg = json!({
+            "ownable": {"owner": CREATOR},
+            "metadata": {"metadata": metadata_msg}
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(deps.as_mut(), env.clone(), info, from_str(&msg).unwrap()).unwrap();
+        // pretend the hub was deployed by a version without seat collections
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.1.0").unwrap();
+        SEAT_CONTRACTS.remove(deps.as_mut().storage);
+        deps.as_mut()
+            .storage
+            .set(b"config", br#"{"owner":"CREATOR"}"#);
+
+        // a dry run reports the changes without applying them
+        let msg = QueryMsg::DryRunMigration { from_version: None };
+        let report: MigrationReport =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert_eq!(report.from_version, "0.1.0");
+        assert_eq!(report.steps.len(), 2);
+        assert_eq!(
+            report.changes,
+            vec![
+                NamespaceChanges {
+                    namespace: "config".to_string(),
+                    updated: 0,
+                    removed: 1,
+                },
+                NamespaceChanges {
+                    namespace: "seat_contracts".to_string(),
+                    updated: 1,
+                    removed: 0,
+                },
+            ]
+        );
+        assert!(SEAT_CONTRACTS
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+
+        let res = migrate(
+            deps.as_mut(),
+            env.clone(),
+            MigrateMsg {
+                owner: Some("new_owner".to_string()),
+            },
+        )
+        .unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "migrations" && attr.value == "0.2.0,0.3.0"));
+
+        // the seat contract is stored as the default collection, the unused
+        // config is dropped
+        assert!(deps.as_ref().storage.get(b"config").is_none());
+        let seats = SEAT_CONTRACTS.load(deps.as_ref().storage).unwrap();
+        assert_eq!(
+            seats,
+            vec![SeatContract {
+                name: "default".to_string(),
+                address: Addr::unchecked(SEAT_CONTRACT),
+            }]
+        );
+
+        // ownership moved to the new owner
+        for (address, expected) in [("new_owner", true), (CREATOR, false)] {
+            let msg = json!({"ownable": {"is_owner": address}}).to_string();
+            let res = query(deps.as_ref(), env.clone(), from_str(&msg).unwrap()).unwrap();
+            match from_binary(&res).unwrap() {
+                OwnableQueryResp::IsOwner(is_owner) => assert_eq!(is_owner, expected),
             }
         }
+
+        let version = cw2::get_contract_version(deps.as_ref().storage).unwrap();
+        assert_eq!(version.version, CONTRACT_VERSION);
+
+        // migrating again to the same version is rejected
+        migrate(deps.as_mut(), env.clone(), MigrateMsg { owner: None }).unwrap_err();
+
+        // and so is migrating from another contract
+        set_contract_version(deps.as_mut().storage, "crates.io:seat", "0.1.0").unwrap();
+        migrate(deps.as_mut(), env, MigrateMsg { owner: None }).unwrap_err();
+    }
+
+    #[test]
+    fn test_error_messages() {
+        let mut deps = mock_dependencies();
+        let metadata_msg = HubMetadata {
+            name: "Kenny's contract".to_string(),
+            hub_url: "find me here".to_string(),
+            description: "Awesome Hub".to_string(),
+            tags: vec![],
+            social_links: vec![],
+            creator: CREATOR.to_string(),
+            thumbnail_image_url: "image link here".to_string(),
+            banner_image_url: "image link here".to_string(),
+            seat_contract: None,
+        };
+        let msg = json!({
+            "metadata": {"metadata": metadata_msg},
+            "ownable": {"owner": CREATOR}
+        })
+        .to_string();
+        let env = mock_env();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+
+        // module errors carry their cause
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("intruder", &[]),
+            ExecuteMsg::Ownable(ownable::ExecuteMsg::SetOwner("intruder".to_string())),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::OwnableError(_)));
+        let message = err.to_string();
+        assert!(message.starts_with("Ownable module error: "));
+        assert!(message.len() > "Ownable module error: ".len());
+
+        // the frontend matches on these
+        assert_eq!(ContractError::Unauthorized {}.to_string(), "Unauthorized");
+        assert_eq!(
+            ContractError::SeatContractMismatch {
+                seat_contract: "seat".to_string(),
+                hub_contract: "hub".to_string(),
+            }
+            .to_string(),
+            "Seat contract seat belongs to hub hub"
+        );
+        assert_eq!(
+            ContractError::InvalidMetadataField {
+                field: "name".to_string(),
+                reason: "cannot be empty".to_string(),
+            }
+            .to_string(),
+            "Invalid name: cannot be empty"
+        );
     }
 }
@@ -9,10 +9,20 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
-    #[error("Ownable Module Error")]
-    OwnableError(ownable::OwnableError),
+    #[error("Ownable module error: {0}")]
+    OwnableError(#[from] ownable::OwnableError),
 
-    #[error("Metadata Module Error")]
-    MetadataError(metadata::MetadataError), // Add any other custom errors you like here.
-                                            // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
+    #[error("Metadata module error: {0}")]
+    MetadataError(#[from] metadata::MetadataError),
+
+    #[error("Seat contract {seat_contract} belongs to hub {hub_contract}")]
+    SeatContractMismatch {
+        seat_contract: String,
+        hub_contract: String,
+    },
+
+    #[error("Invalid {field}: {reason}")]
+    InvalidMetadataField { field: String, reason: String },
+    // Add any other custom errors you like here.
+    // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
 }
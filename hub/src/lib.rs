@@ -1,5 +1,6 @@
 pub mod contract;
 mod error;
+pub mod migrate;
 pub mod msg;
 pub mod state;
 
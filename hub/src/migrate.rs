@@ -0,0 +1,76 @@
+use cosmwasm_std::{Deps, DepsMut, StdResult};
+use cw_storage_plus::Item;
+use migrations::{parse_version, transform_item, Migration, MigrationReport};
+
+use crate::contract::CONTRACT_VERSION;
+use crate::state::{HubMetadata, SeatContract, DEFAULT_SEAT_COLLECTION, SEAT_CONTRACTS};
+
+/// Storage migrations of the hub, ordered by version
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: "0.1.0",
+        to: "0.2.0",
+        description: "Store the seat collections",
+        migrate: store_seat_contracts,
+    },
+    Migration {
+        from: "0.2.0",
+        to: "0.3.0",
+        description: "Mirror the primary seat collection in the metadata, drop unused items",
+        migrate: sync_metadata,
+    },
+];
+
+/// Reports what migrating from `from_version`, by default the deployed
+/// version, to this version would change
+pub fn dry_run_migration(deps: Deps, from_version: Option<String>) -> StdResult<MigrationReport> {
+    let from_version = match from_version {
+        Some(version) => version,
+        None => cw2::get_contract_version(deps.storage)?.version,
+    };
+    migrations::dry_run(
+        deps,
+        MIGRATIONS,
+        &parse_version(&from_version)?,
+        &parse_version(CONTRACT_VERSION)?,
+    )
+}
+
+/// 0.2.0 stores the seat collections in `SEAT_CONTRACTS`. Older hubs only
+/// know `HubMetadata::seat_contract`, which becomes the default collection.
+fn store_seat_contracts(deps: DepsMut) -> StdResult<()> {
+    if SEAT_CONTRACTS.may_load(deps.storage)?.is_some() {
+        return Ok(());
+    }
+    // stored by the metadata module
+    let metadata: HubMetadata = Item::new("metadata").load(deps.storage)?;
+    let seats = metadata
+        .seat_contract
+        .map(|address| {
+            vec![SeatContract {
+                name: DEFAULT_SEAT_COLLECTION.to_string(),
+                address,
+            }]
+        })
+        .unwrap_or_default();
+    SEAT_CONTRACTS.save(deps.storage, &seats)
+}
+
+/// 0.3.0 keeps `HubMetadata::seat_contract` pointing at the first seat
+/// collection. The `config` and `seat_contract` items are no longer read,
+/// ownership lives in the ownable module.
+fn sync_metadata(deps: DepsMut) -> StdResult<()> {
+    let primary = SEAT_CONTRACTS
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .into_iter()
+        .next()
+        .map(|seat| seat.address);
+    transform_item(deps.storage, "metadata", |mut metadata: HubMetadata| {
+        metadata.seat_contract = primary;
+        Ok(metadata)
+    })?;
+    deps.storage.remove(b"config");
+    deps.storage.remove(b"seat_contract");
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
 use serde::{Deserialize, Serialize};
 
 use crate::state::{HubMetadata, MetadataField};
@@ -14,15 +15,108 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg {
     Ownable(ownable::ExecuteMsg),
     UpdateMetadata(MetadataField),
+    /// Appends a named seat collection to the hub
+    AddSeatContract {
+        name: String,
+        address: String,
+    },
+    RemoveSeatContract {
+        name: String,
+    },
+    /// Reorders the seat collections, `names` must list all of them
+    ReorderSeatContracts {
+        names: Vec<String>,
+    },
 }
 
 #[cw_serde]
 pub struct MigrateMsg {
-    pub owner: String,
+    /// Transfers the hub to this address when set
+    pub owner: Option<String>,
 }
 
 #[cw_serde]
 pub enum QueryMsg {
     Ownable(ownable::QueryMsg),
     Metadata(metadata::QueryMsg),
+    /// Returns the seat collections of the hub in display order
+    SeatContracts {},
+    /// Returns whether `address` holds a seat in any of the hub's collections
+    IsMember {
+        address: String,
+    },
+    /// Lists the holders of the hub's seats, going through the seat
+    /// collections in display order. Pages hold up to `limit` seats, a member
+    /// whose seats span several pages is listed on each of them.
+    Members {
+        start_after: Option<MembersCursor>,
+        limit: Option<u32>,
+    },
+    /// Reports what migrating from `from_version`, by default the deployed
+    /// version, to this version would change, without changing anything
+    DryRunMigration {
+        from_version: Option<String>,
+    },
+}
+
+/// The seats a member holds in one of the hub's seat collections
+#[cw_serde]
+pub struct MemberSeats {
+    pub seat_contract: Addr,
+    pub name: String,
+    pub token_ids: Vec<String>,
+}
+
+#[cw_serde]
+pub struct IsMemberResponse {
+    pub is_member: bool,
+    pub seats: Vec<MemberSeats>,
+}
+
+#[cw_serde]
+pub struct Member {
+    pub address: Addr,
+    pub seats: Vec<MemberSeats>,
+}
+
+/// The last seat a `Members` page went through
+#[cw_serde]
+pub struct MembersCursor {
+    /// Name of the seat collection
+    pub collection: String,
+    pub token_id: String,
+}
+
+#[cw_serde]
+pub struct MembersResponse {
+    pub members: Vec<Member>,
+    /// Where the next page starts, `None` on the last page
+    pub next_start_after: Option<MembersCursor>,
+}
+
+/// Queries the hub sends to its seat contracts
+#[cw_serde]
+pub enum SeatQueryMsg {
+    HubContract {},
+    AllSeats {
+        start_after: Option<String>,
+        limit: Option<u32>,
+        owner: Option<String>,
+    },
+}
+
+/// The part of a seat contract `SeatInfo` the hub relies on
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SeatHolder {
+    pub token_id: String,
+    pub owner: Addr,
+    /// False once the seat has expired, missing from older seat contracts
+    pub active: Option<bool>,
+}
+
+/// The part of a seat contract `AllSeatsResponse` the hub relies on
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SeatHoldersResponse {
+    pub seats: Vec<SeatHolder>,
+    pub next_start_after: Option<String>,
 }
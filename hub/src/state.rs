@@ -2,22 +2,26 @@ use std::{cell::RefCell, rc::Rc};
 
 use burnt_glue::module::Module;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Response, StdError,
+    StdResult,
+};
 use cw_storage_plus::Item;
+use migrations::module_query_error;
 use ownable::Ownable;
+use schemars::JsonSchema;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    migrate::dry_run_migration,
+    msg::{
+        ExecuteMsg, InstantiateMsg, IsMemberResponse, Member, MemberSeats, MembersCursor,
+        MembersResponse, QueryMsg, SeatHolder, SeatHoldersResponse, SeatQueryMsg,
+    },
     ContractError,
 };
 
-#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
-pub struct Config {
-    pub owner: Addr,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, JsonSchema)]
 pub struct SocialLinks {
     pub name: String,
     pub url: String,
@@ -38,34 +42,202 @@ pub struct HubMetadata {
 #[cw_serde]
 pub enum MetadataField {
     SeatContract(String),
+    Name(String),
+    HubUrl(String),
+    Description(String),
+    Tags(Vec<String>),
+    AddTag(String),
+    RemoveTag(String),
+    SocialLinks(Vec<SocialLinks>),
+    AddSocialLink(SocialLinks),
+    /// Removes the social link with the given name
+    RemoveSocialLink(String),
+    Creator(String),
+    ThumbnailImageUrl(String),
+    BannerImageUrl(String),
 }
 
-impl<'a> HubMetadata {
-    pub fn update_seat_contract(
-        self,
-        modules: &mut HubModules<'a, HubMetadata>,
-        deps: &mut DepsMut,
-        env: Env,
-        info: MessageInfo,
-        address: &str,
-    ) -> Result<Response, ContractError> {
-        let new_metadata = HubMetadata {
-            seat_contract: Some(deps.api.addr_validate(address)?),
-            ..self
+const MAX_NAME_LENGTH: usize = 64;
+const MAX_DESCRIPTION_LENGTH: usize = 1024;
+const MAX_TAG_LENGTH: usize = 32;
+const MAX_TAGS: usize = 16;
+const MAX_SOCIAL_LINKS: usize = 16;
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+fn validate_text(field: &str, value: &str, max_length: usize) -> Result<(), ContractError> {
+    if value.trim().is_empty() {
+        return Err(ContractError::InvalidMetadataField {
+            field: field.to_string(),
+            reason: "cannot be empty".to_string(),
+        });
+    }
+    if value.len() > max_length {
+        return Err(ContractError::InvalidMetadataField {
+            field: field.to_string(),
+            reason: format!("cannot be longer than {} characters", max_length),
+        });
+    }
+    Ok(())
+}
+
+fn validate_tags(tags: &[String]) -> Result<(), ContractError> {
+    if tags.len() > MAX_TAGS {
+        return Err(ContractError::InvalidMetadataField {
+            field: "tags".to_string(),
+            reason: format!("cannot have more than {} tags", MAX_TAGS),
+        });
+    }
+    for (i, tag) in tags.iter().enumerate() {
+        validate_text("tags", tag, MAX_TAG_LENGTH)?;
+        if tags[..i].contains(tag) {
+            return Err(ContractError::InvalidMetadataField {
+                field: "tags".to_string(),
+                reason: format!("duplicate tag {}", tag),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn validate_social_links(links: &[SocialLinks]) -> Result<(), ContractError> {
+    if links.len() > MAX_SOCIAL_LINKS {
+        return Err(ContractError::InvalidMetadataField {
+            field: "social_links".to_string(),
+            reason: format!("cannot have more than {} social links", MAX_SOCIAL_LINKS),
+        });
+    }
+    for (i, link) in links.iter().enumerate() {
+        validate_text("social_links", &link.name, MAX_NAME_LENGTH)?;
+        validate_text("social_links", &link.url, MAX_DESCRIPTION_LENGTH)?;
+        if links[..i].iter().any(|l| l.name == link.name) {
+            return Err(ContractError::InvalidMetadataField {
+                field: "social_links".to_string(),
+                reason: format!("duplicate social link {}", link.name),
+            });
+        }
+    }
+    Ok(())
+}
+
+impl HubMetadata {
+    /// Applies a single field update, returning the updated metadata together
+    /// with the name and new value of the changed field
+    pub fn update_field(
+        mut self,
+        deps: Deps,
+        env: &Env,
+        field: MetadataField,
+    ) -> Result<(HubMetadata, String, String), ContractError> {
+        let (name, value) = match field {
+            MetadataField::SeatContract(address) => {
+                let address = deps.api.addr_validate(&address)?;
+                verify_seat_contract(&deps.querier, &address, &env.contract.address)?;
+                self.seat_contract = Some(address.clone());
+                ("seat_contract", address.to_string())
+            }
+            MetadataField::Name(name) => {
+                validate_text("name", &name, MAX_NAME_LENGTH)?;
+                self.name = name.clone();
+                ("name", name)
+            }
+            MetadataField::HubUrl(url) => {
+                validate_text("hub_url", &url, MAX_DESCRIPTION_LENGTH)?;
+                self.hub_url = url.clone();
+                ("hub_url", url)
+            }
+            MetadataField::Description(description) => {
+                validate_text("description", &description, MAX_DESCRIPTION_LENGTH)?;
+                self.description = description.clone();
+                ("description", description)
+            }
+            MetadataField::Tags(tags) => {
+                validate_tags(&tags)?;
+                self.tags = tags;
+                ("tags", self.tags.join(","))
+            }
+            MetadataField::AddTag(tag) => {
+                self.tags.push(tag);
+                validate_tags(&self.tags)?;
+                ("tags", self.tags.join(","))
+            }
+            MetadataField::RemoveTag(tag) => {
+                let len = self.tags.len();
+                self.tags.retain(|t| *t != tag);
+                if self.tags.len() == len {
+                    return Err(ContractError::InvalidMetadataField {
+                        field: "tags".to_string(),
+                        reason: format!("tag {} not found", tag),
+                    });
+                }
+                ("tags", self.tags.join(","))
+            }
+            MetadataField::SocialLinks(links) => {
+                validate_social_links(&links)?;
+                self.social_links = links;
+                ("social_links", social_links_value(&self.social_links))
+            }
+            MetadataField::AddSocialLink(link) => {
+                self.social_links.push(link);
+                validate_social_links(&self.social_links)?;
+                ("social_links", social_links_value(&self.social_links))
+            }
+            MetadataField::RemoveSocialLink(name) => {
+                let len = self.social_links.len();
+                self.social_links.retain(|l| l.name != name);
+                if self.social_links.len() == len {
+                    return Err(ContractError::InvalidMetadataField {
+                        field: "social_links".to_string(),
+                        reason: format!("social link {} not found", name),
+                    });
+                }
+                ("social_links", social_links_value(&self.social_links))
+            }
+            MetadataField::Creator(creator) => {
+                validate_text("creator", &creator, MAX_NAME_LENGTH)?;
+                self.creator = creator.clone();
+                ("creator", creator)
+            }
+            MetadataField::ThumbnailImageUrl(url) => {
+                validate_text("thumbnail_image_url", &url, MAX_DESCRIPTION_LENGTH)?;
+                self.thumbnail_image_url = url.clone();
+                ("thumbnail_image_url", url)
+            }
+            MetadataField::BannerImageUrl(url) => {
+                validate_text("banner_image_url", &url, MAX_DESCRIPTION_LENGTH)?;
+                self.banner_image_url = url.clone();
+                ("banner_image_url", url)
+            }
         };
+        Ok((self, name.to_string(), value))
+    }
+}
 
-        modules
-            .metadata
-            .execute(
-                deps,
-                env,
-                info,
-                metadata::ExecuteMsg::SetMetadata(new_metadata),
-            )
-            .map_err(ContractError::MetadataError)
-            .map(|_| Response::default()) // convert the glue result into a cosmwasm_std response
+/// Errors unless the seat contract at `seat_contract` was instantiated for `hub`
+pub fn verify_seat_contract(
+    querier: &QuerierWrapper,
+    seat_contract: &Addr,
+    hub: &Addr,
+) -> Result<(), ContractError> {
+    let seat_hub: Addr = querier.query_wasm_smart(seat_contract, &SeatQueryMsg::HubContract {})?;
+    if seat_hub != *hub {
+        return Err(ContractError::SeatContractMismatch {
+            seat_contract: seat_contract.to_string(),
+            hub_contract: seat_hub.to_string(),
+        });
     }
+    Ok(())
 }
+
+fn social_links_value(links: &[SocialLinks]) -> String {
+    links
+        .iter()
+        .map(|l| l.name.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 pub struct HubModules<'a, T>
 where
     T: Serialize + DeserializeOwned,
@@ -74,7 +246,19 @@ where
     pub metadata: metadata::Metadata<'a, T>,
 }
 
-pub const SEAT_CONTRACT: Item<Addr> = Item::new("seat_contract");
+/// A seat collection of the hub, e.g. a "Founders" or "Supporters" tier
+#[cw_serde]
+pub struct SeatContract {
+    pub name: String,
+    pub address: Addr,
+}
+
+/// Name given to the seat contract set through `MetadataField::SeatContract`
+pub const DEFAULT_SEAT_COLLECTION: &str = "default";
+
+/// Seat collections in display order. The first one is mirrored in
+/// `HubMetadata::seat_contract` for clients that only know a single collection.
+pub const SEAT_CONTRACTS: Item<Vec<SeatContract>> = Item::new("seat_contracts");
 
 impl<'a> Default for HubModules<'a, HubMetadata> {
     fn default() -> Self {
@@ -139,42 +323,412 @@ impl<'a> HubModules<'a, HubMetadata> {
                 .map(|_| Response::default()),
 
             ExecuteMsg::UpdateMetadata(meta_field) => {
-                // get previous metadata
-                let old_meta = self
-                    .metadata
-                    .query(
-                        &mut_deps.as_ref().as_ref(),
-                        env.clone(),
-                        metadata::QueryMsg::GetMetadata {},
-                    )
-                    .unwrap();
-                match meta_field {
-                    MetadataField::SeatContract(address) => match old_meta {
-                        metadata::QueryResp::Metadata(meta) => meta.update_seat_contract(
-                            self,
-                            &mut mut_deps,
-                            env,
-                            info,
-                            address.as_str(),
-                        ),
-                    },
-                }
+                self.update_metadata(&mut mut_deps, env, info, meta_field)
+            }
+
+            ExecuteMsg::AddSeatContract { name, address } => {
+                self.add_seat_contract(&mut mut_deps, env, info, name, address)
             }
+
+            ExecuteMsg::RemoveSeatContract { name } => {
+                self.remove_seat_contract(&mut mut_deps, env, info, name)
+            }
+
+            ExecuteMsg::ReorderSeatContracts { names } => {
+                self.reorder_seat_contracts(&mut mut_deps, env, info, names)
+            }
+        }
+    }
+
+    /// Errors unless `sender` is the owner recorded by the ownable module
+    pub fn ensure_owner(&self, deps: Deps, env: &Env, sender: &Addr) -> Result<(), ContractError> {
+        let resp = self
+            .ownable
+            .query(
+                &deps,
+                env.clone(),
+                ownable::QueryMsg::IsOwner(sender.to_string()),
+            )
+            .map_err(ContractError::OwnableError)?;
+        match resp {
+            ownable::QueryResp::IsOwner(true) => Ok(()),
+            ownable::QueryResp::IsOwner(false) => Err(ContractError::Unauthorized {}),
         }
     }
 
+    pub fn load_metadata(&self, deps: Deps, env: &Env) -> Result<HubMetadata, ContractError> {
+        match self
+            .metadata
+            .query(&deps, env.clone(), metadata::QueryMsg::GetMetadata {})
+            .map_err(ContractError::MetadataError)?
+        {
+            metadata::QueryResp::Metadata(meta) => Ok(meta),
+        }
+    }
+
+    pub fn update_metadata(
+        &mut self,
+        deps: &mut DepsMut,
+        env: Env,
+        info: MessageInfo,
+        meta_field: MetadataField,
+    ) -> Result<Response, ContractError> {
+        self.ensure_owner(deps.as_ref(), &env, &info.sender)?;
+        // get previous metadata
+        let old_meta = self.load_metadata(deps.as_ref(), &env)?;
+        let sets_seat_contract = matches!(meta_field, MetadataField::SeatContract(_));
+        // the single seat contract field only manages the default collection,
+        // named collections are never repointed through it
+        if sets_seat_contract
+            && self
+                .load_seat_contracts(deps.as_ref(), &env)?
+                .iter()
+                .any(|seat| seat.name != DEFAULT_SEAT_COLLECTION)
+        {
+            return Err(ContractError::InvalidMetadataField {
+                field: "seat_contract".to_string(),
+                reason: "named seat contracts are managed individually".to_string(),
+            });
+        }
+        let (new_meta, field, value) = old_meta.update_field(deps.as_ref(), &env, meta_field)?;
+
+        if let (true, Some(address)) = (sets_seat_contract, &new_meta.seat_contract) {
+            let seats = vec![SeatContract {
+                name: DEFAULT_SEAT_COLLECTION.to_string(),
+                address: address.clone(),
+            }];
+            SEAT_CONTRACTS.save(deps.storage, &seats)?;
+        }
+
+        self.metadata
+            .execute(deps, env, info, metadata::ExecuteMsg::SetMetadata(new_meta))
+            .map_err(ContractError::MetadataError)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "update_metadata")
+            .add_attribute("field", field)
+            .add_attribute("value", value))
+    }
+
+    /// The seat collections of the hub. Hubs created before multiple
+    /// collections were supported only have `HubMetadata::seat_contract`,
+    /// which is listed as the default collection.
+    pub fn load_seat_contracts(
+        &self,
+        deps: Deps,
+        env: &Env,
+    ) -> Result<Vec<SeatContract>, ContractError> {
+        if let Some(seats) = SEAT_CONTRACTS.may_load(deps.storage)? {
+            return Ok(seats);
+        }
+        let seats = self
+            .load_metadata(deps, env)?
+            .seat_contract
+            .map(|address| {
+                vec![SeatContract {
+                    name: DEFAULT_SEAT_COLLECTION.to_string(),
+                    address,
+                }]
+            })
+            .unwrap_or_default();
+        Ok(seats)
+    }
+
+    /// Saves the seat collections and mirrors the primary one in the metadata
+    fn save_seat_contracts(
+        &mut self,
+        deps: &mut DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        seats: Vec<SeatContract>,
+    ) -> Result<(), ContractError> {
+        let mut meta = self.load_metadata(deps.as_ref(), env)?;
+        let primary = seats.first().map(|seat| seat.address.clone());
+        SEAT_CONTRACTS.save(deps.storage, &seats)?;
+        if meta.seat_contract != primary {
+            meta.seat_contract = primary;
+            self.metadata
+                .execute(
+                    deps,
+                    env.clone(),
+                    info.clone(),
+                    metadata::ExecuteMsg::SetMetadata(meta),
+                )
+                .map_err(ContractError::MetadataError)?;
+        }
+        Ok(())
+    }
+
+    pub fn add_seat_contract(
+        &mut self,
+        deps: &mut DepsMut,
+        env: Env,
+        info: MessageInfo,
+        name: String,
+        address: String,
+    ) -> Result<Response, ContractError> {
+        self.ensure_owner(deps.as_ref(), &env, &info.sender)?;
+        validate_text("seat_contracts", &name, MAX_NAME_LENGTH)?;
+        let address = deps.api.addr_validate(&address)?;
+        verify_seat_contract(&deps.querier, &address, &env.contract.address)?;
+
+        let mut seats = self.load_seat_contracts(deps.as_ref(), &env)?;
+        if seats
+            .iter()
+            .any(|seat| seat.name == name || seat.address == address)
+        {
+            return Err(ContractError::InvalidMetadataField {
+                field: "seat_contracts".to_string(),
+                reason: format!("seat contract {} already added", name),
+            });
+        }
+        seats.push(SeatContract {
+            name: name.clone(),
+            address: address.clone(),
+        });
+        self.save_seat_contracts(deps, &env, &info, seats)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "add_seat_contract")
+            .add_attribute("name", name)
+            .add_attribute("address", address))
+    }
+
+    pub fn remove_seat_contract(
+        &mut self,
+        deps: &mut DepsMut,
+        env: Env,
+        info: MessageInfo,
+        name: String,
+    ) -> Result<Response, ContractError> {
+        self.ensure_owner(deps.as_ref(), &env, &info.sender)?;
+        let mut seats = self.load_seat_contracts(deps.as_ref(), &env)?;
+        let len = seats.len();
+        seats.retain(|seat| seat.name != name);
+        if seats.len() == len {
+            return Err(ContractError::InvalidMetadataField {
+                field: "seat_contracts".to_string(),
+                reason: format!("seat contract {} not found", name),
+            });
+        }
+        self.save_seat_contracts(deps, &env, &info, seats)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "remove_seat_contract")
+            .add_attribute("name", name))
+    }
+
+    pub fn reorder_seat_contracts(
+        &mut self,
+        deps: &mut DepsMut,
+        env: Env,
+        info: MessageInfo,
+        names: Vec<String>,
+    ) -> Result<Response, ContractError> {
+        self.ensure_owner(deps.as_ref(), &env, &info.sender)?;
+        let mut seats = self.load_seat_contracts(deps.as_ref(), &env)?;
+        let mut sorted_names = names.clone();
+        sorted_names.sort();
+        let mut current_names: Vec<String> = seats.iter().map(|seat| seat.name.clone()).collect();
+        current_names.sort();
+        if sorted_names != current_names {
+            return Err(ContractError::InvalidMetadataField {
+                field: "seat_contracts".to_string(),
+                reason: "order must list every seat contract exactly once".to_string(),
+            });
+        }
+        seats.sort_by_key(|seat| names.iter().position(|name| *name == seat.name));
+        self.save_seat_contracts(deps, &env, &info, seats)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "reorder_seat_contracts")
+            .add_attribute("order", names.join(",")))
+    }
+
     pub fn query(&self, deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         match msg {
-            QueryMsg::Ownable(query_msg) => self
-                .ownable
-                .query(&deps, env, query_msg)
-                .map(|res| to_binary(&res))
-                .unwrap(),
-            QueryMsg::Metadata(query_msg) => self
-                .metadata
-                .query(&deps, env, query_msg)
-                .map(|res| to_binary(&res))
-                .unwrap(),
+            QueryMsg::Ownable(query_msg) => to_binary(
+                &self
+                    .ownable
+                    .query(&deps, env, query_msg)
+                    .map_err(|err| module_query_error("ownable", err))?,
+            ),
+            QueryMsg::Metadata(query_msg) => to_binary(
+                &self
+                    .metadata
+                    .query(&deps, env, query_msg)
+                    .map_err(|err| module_query_error("metadata", err))?,
+            ),
+            QueryMsg::SeatContracts {} => to_binary(
+                &self
+                    .load_seat_contracts(deps, &env)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?,
+            ),
+            QueryMsg::IsMember { address } => to_binary(&self.is_member(deps, env, address)?),
+            QueryMsg::Members { start_after, limit } => {
+                to_binary(&self.members(deps, env, start_after, limit)?)
+            }
+            QueryMsg::DryRunMigration { from_version } => {
+                to_binary(&dry_run_migration(deps, from_version)?)
+            }
+        }
+    }
+
+    pub fn is_member(&self, deps: Deps, env: Env, address: String) -> StdResult<IsMemberResponse> {
+        let address = deps.api.addr_validate(&address)?;
+        let seat_contracts = self
+            .load_seat_contracts(deps, &env)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        let mut seats = vec![];
+        for seat_contract in seat_contracts {
+            let token_ids: Vec<String> =
+                query_seat_holders(&deps.querier, &seat_contract.address, &address)?
+                    .into_iter()
+                    .map(|holder| holder.token_id)
+                    .collect();
+            if !token_ids.is_empty() {
+                seats.push(MemberSeats {
+                    seat_contract: seat_contract.address,
+                    name: seat_contract.name,
+                    token_ids,
+                });
+            }
+        }
+        Ok(IsMemberResponse {
+            is_member: !seats.is_empty(),
+            seats,
+        })
+    }
+
+    pub fn members(
+        &self,
+        deps: Deps,
+        env: Env,
+        start_after: Option<MembersCursor>,
+        limit: Option<u32>,
+    ) -> StdResult<MembersResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+        let seat_contracts = self
+            .load_seat_contracts(deps, &env)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        let (mut index, mut token_start) = match start_after {
+            Some(cursor) => {
+                let index = seat_contracts
+                    .iter()
+                    .position(|seat| seat.name == cursor.collection)
+                    .ok_or_else(|| {
+                        StdError::generic_err(format!(
+                            "unknown seat collection {}",
+                            cursor.collection
+                        ))
+                    })?;
+                (index, Some(cursor.token_id))
+            }
+            None => (0, None),
+        };
+
+        // each seat contract is queried at most once per page
+        let mut members: Vec<Member> = vec![];
+        let mut remaining = limit;
+        let mut next_start_after = None;
+        while let Some(seat_contract) = seat_contracts.get(index) {
+            let res: SeatHoldersResponse = deps.querier.query_wasm_smart(
+                &seat_contract.address,
+                &SeatQueryMsg::AllSeats {
+                    start_after: token_start.take(),
+                    limit: Some(remaining),
+                    owner: None,
+                },
+            )?;
+            remaining = remaining.saturating_sub(res.seats.len() as u32);
+            let last_token_id = res.seats.last().map(|holder| holder.token_id.clone());
+            for holder in res.seats {
+                if holder.active.unwrap_or(true) {
+                    add_member_seat(&mut members, seat_contract, holder);
+                }
+            }
+            // the seat contract stopped before the end of its seats
+            if let Some(token_id) = res.next_start_after {
+                next_start_after = Some(MembersCursor {
+                    collection: seat_contract.name.clone(),
+                    token_id,
+                });
+                break;
+            }
+            index += 1;
+            if remaining == 0 {
+                next_start_after = match (seat_contracts.get(index), last_token_id) {
+                    (Some(_), Some(token_id)) => Some(MembersCursor {
+                        collection: seat_contract.name.clone(),
+                        token_id,
+                    }),
+                    _ => None,
+                };
+                break;
+            }
+        }
+        Ok(MembersResponse {
+            members,
+            next_start_after,
+        })
+    }
+}
+
+/// Adds the seat `holder` of `seat_contract` to the member holding it
+fn add_member_seat(members: &mut Vec<Member>, seat_contract: &SeatContract, holder: SeatHolder) {
+    let index = match members
+        .iter()
+        .position(|member| member.address == holder.owner)
+    {
+        Some(index) => index,
+        None => {
+            members.push(Member {
+                address: holder.owner,
+                seats: vec![],
+            });
+            members.len() - 1
+        }
+    };
+    let seats = &mut members[index].seats;
+    match seats
+        .iter_mut()
+        .find(|seats| seats.seat_contract == seat_contract.address)
+    {
+        Some(seats) => seats.token_ids.push(holder.token_id),
+        None => seats.push(MemberSeats {
+            seat_contract: seat_contract.address.clone(),
+            name: seat_contract.name.clone(),
+            token_ids: vec![holder.token_id],
+        }),
+    }
+}
+
+/// Every active seat `owner` holds in `seat_contract`. Expired seats do not
+/// make their owner a member.
+fn query_seat_holders(
+    querier: &QuerierWrapper,
+    seat_contract: &Addr,
+    owner: &Addr,
+) -> StdResult<Vec<SeatHolder>> {
+    let mut holders = vec![];
+    let mut start_after = None;
+    loop {
+        let res: SeatHoldersResponse = querier.query_wasm_smart(
+            seat_contract,
+            &SeatQueryMsg::AllSeats {
+                start_after,
+                limit: Some(MAX_LIMIT),
+                owner: Some(owner.to_string()),
+            },
+        )?;
+        holders.extend(
+            res.seats
+                .into_iter()
+                .filter(|holder| holder.active.unwrap_or(true)),
+        );
+        match res.next_start_after {
+            Some(next) => start_after = Some(next),
+            None => return Ok(holders),
         }
     }
 }
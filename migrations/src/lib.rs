@@ -0,0 +1,430 @@
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use burnt_glue::module::Module;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Order, Record, StdError, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+use ownable::Ownable;
+use semver::Version;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A storage migration from the layout written by version `from` to the one
+/// expected by version `to`
+pub struct Migration {
+    pub from: &'static str,
+    pub to: &'static str,
+    pub description: &'static str,
+    pub migrate: fn(DepsMut) -> StdResult<()>,
+}
+
+#[cw_serde]
+pub struct MigrationStep {
+    pub from: String,
+    pub to: String,
+    pub description: String,
+}
+
+/// Entries of a storage namespace (an item or a map) a migration would change
+#[cw_serde]
+pub struct NamespaceChanges {
+    pub namespace: String,
+    pub updated: u32,
+    pub removed: u32,
+}
+
+#[cw_serde]
+pub struct MigrationReport {
+    pub from_version: String,
+    pub to_version: String,
+    pub steps: Vec<MigrationStep>,
+    pub changes: Vec<NamespaceChanges>,
+}
+
+pub fn parse_version(version: &str) -> StdResult<Version> {
+    Version::parse(version)
+        .map_err(|err| StdError::generic_err(format!("Invalid version {}: {}", version, err)))
+}
+
+/// Wraps an error returned by one of a contract's modules so queries fail with
+/// a `StdError` naming the module instead of aborting the contract
+pub fn module_query_error(module: &str, err: impl std::fmt::Display) -> StdError {
+    StdError::generic_err(format!("{} query failed: {}", module, err))
+}
+
+/// The steps of `registry` upgrading a contract from version `from` to `to`,
+/// in order. `registry` must be ordered by version.
+pub fn plan<'a>(
+    registry: &'a [Migration],
+    from: &Version,
+    to: &Version,
+) -> StdResult<Vec<&'a Migration>> {
+    let mut current = from.clone();
+    let mut steps = vec![];
+    for migration in registry {
+        let step_from = parse_version(migration.from)?;
+        let step_to = parse_version(migration.to)?;
+        if step_from >= step_to {
+            return Err(StdError::generic_err(format!(
+                "Invalid migration from {} to {}",
+                step_from, step_to
+            )));
+        }
+        if step_to <= current || step_to > *to {
+            continue;
+        }
+        if step_from > current {
+            return Err(StdError::generic_err(format!(
+                "No migration from {} to {}",
+                current, step_from
+            )));
+        }
+        current = step_to;
+        steps.push(migration);
+    }
+    Ok(steps)
+}
+
+/// Runs the steps of `registry` between `from` and `to`, then hands the
+/// contract over to `owner` if set. Returns the steps run.
+pub fn migrate<'a>(
+    mut deps: DepsMut,
+    env: &Env,
+    registry: &'a [Migration],
+    from: &Version,
+    to: &Version,
+    owner: Option<&str>,
+) -> StdResult<Vec<&'a Migration>> {
+    let steps = plan(registry, from, to)?;
+    for step in &steps {
+        (step.migrate)(deps.branch())?;
+    }
+    if let Some(owner) = owner {
+        transfer_ownership(deps, env, owner)?;
+    }
+    Ok(steps)
+}
+
+/// Hands the contract over to `owner` through its ownable module. When
+/// migrating, the chain admin rather than the current owner authorises it.
+pub fn transfer_ownership(mut deps: DepsMut, env: &Env, owner: &str) -> StdResult<()> {
+    let owner = deps.api.addr_validate(owner)?;
+    let info = MessageInfo {
+        sender: env.contract.address.clone(),
+        funds: vec![],
+    };
+    Ownable::default()
+        .instantiate(
+            &mut deps,
+            env,
+            &info,
+            ownable::InstantiateMsg {
+                owner: owner.to_string(),
+            },
+        )
+        .map_err(|err| StdError::generic_err(format!("ownership transfer failed: {}", err)))?;
+    Ok(())
+}
+
+/// Reports what migrating from `from` to `to` would change without writing
+/// to `deps.storage`
+pub fn dry_run(
+    deps: Deps,
+    registry: &[Migration],
+    from: &Version,
+    to: &Version,
+) -> StdResult<MigrationReport> {
+    let steps = plan(registry, from, to)?;
+    let mut storage = DryRunStorage::new(deps.storage);
+    for step in &steps {
+        (step.migrate)(DepsMut {
+            storage: &mut storage,
+            api: deps.api,
+            querier: deps.querier,
+        })?;
+    }
+    Ok(MigrationReport {
+        from_version: from.to_string(),
+        to_version: to.to_string(),
+        steps: steps
+            .into_iter()
+            .map(|step| MigrationStep {
+                from: step.from.to_string(),
+                to: step.to.to_string(),
+                description: step.description.to_string(),
+            })
+            .collect(),
+        changes: storage.changes(),
+    })
+}
+
+/// Rewrites the item stored under `key`. Returns `false` if it is not set.
+pub fn transform_item<O, N>(
+    storage: &mut dyn Storage,
+    key: &str,
+    transform: impl FnOnce(O) -> StdResult<N>,
+) -> StdResult<bool>
+where
+    O: Serialize + DeserializeOwned,
+    N: Serialize + DeserializeOwned,
+{
+    let old = match Item::<O>::new(key).may_load(storage)? {
+        Some(old) => old,
+        None => return Ok(false),
+    };
+    Item::<N>::new(key).save(storage, &transform(old)?)?;
+    Ok(true)
+}
+
+/// Rewrites every entry of the map stored under `namespace`, whatever its key
+/// type. Entries mapped to `None` are removed. Returns the entries visited.
+pub fn transform_map<O, N>(
+    storage: &mut dyn Storage,
+    namespace: &str,
+    mut transform: impl FnMut(&[u8], O) -> StdResult<Option<N>>,
+) -> StdResult<u32>
+where
+    O: Serialize + DeserializeOwned,
+    N: Serialize + DeserializeOwned,
+{
+    let entries = Map::<Vec<u8>, O>::new(namespace)
+        .range_raw(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let map = Map::<Vec<u8>, N>::new(namespace);
+    let mut visited = 0;
+    for (key, old) in entries {
+        match transform(&key, old)? {
+            Some(new) => map.save(storage, key, &new)?,
+            None => map.remove(storage, key),
+        }
+        visited += 1;
+    }
+    Ok(visited)
+}
+
+/// Copy-on-write view of a storage, writes are kept in memory
+struct DryRunStorage<'a> {
+    base: &'a dyn Storage,
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> DryRunStorage<'a> {
+    fn new(base: &'a dyn Storage) -> Self {
+        DryRunStorage {
+            base,
+            writes: BTreeMap::new(),
+        }
+    }
+
+    /// Writes that differ from the base storage, grouped by namespace
+    fn changes(&self) -> Vec<NamespaceChanges> {
+        let mut changes: BTreeMap<String, NamespaceChanges> = BTreeMap::new();
+        for (key, value) in &self.writes {
+            if self.base.get(key) == *value {
+                continue;
+            }
+            let namespace = namespace_of(key);
+            let entry = changes
+                .entry(namespace.clone())
+                .or_insert(NamespaceChanges {
+                    namespace,
+                    updated: 0,
+                    removed: 0,
+                });
+            match value {
+                Some(_) => entry.updated += 1,
+                None => entry.removed += 1,
+            }
+        }
+        changes.into_values().collect()
+    }
+}
+
+impl Storage for DryRunStorage<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.writes.get(key) {
+            Some(value) => value.clone(),
+            None => self.base.get(key),
+        }
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        if let (Some(start), Some(end)) = (start, end) {
+            if start >= end {
+                return Box::new(std::iter::empty());
+            }
+        }
+        let mut records: BTreeMap<Vec<u8>, Vec<u8>> =
+            self.base.range(start, end, Order::Ascending).collect();
+        let lower = match start {
+            Some(start) => Bound::Included(start.to_vec()),
+            None => Bound::Unbounded,
+        };
+        let upper = match end {
+            Some(end) => Bound::Excluded(end.to_vec()),
+            None => Bound::Unbounded,
+        };
+        for (key, value) in self.writes.range((lower, upper)) {
+            match value {
+                Some(value) => records.insert(key.clone(), value.clone()),
+                None => records.remove(key),
+            };
+        }
+        match order {
+            Order::Ascending => Box::new(records.into_iter()),
+            Order::Descending => Box::new(records.into_iter().rev()),
+        }
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.writes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.writes.insert(key.to_vec(), None);
+    }
+}
+
+/// Items are stored under their raw key, map entries under their namespace
+/// prefixed with its length
+fn namespace_of(key: &[u8]) -> String {
+    if key.len() > 2 {
+        let length = u16::from_be_bytes([key[0], key[1]]) as usize;
+        if length > 0 && key.len() >= 2 + length {
+            if let Ok(namespace) = std::str::from_utf8(&key[2..2 + length]) {
+                return namespace.to_string();
+            }
+        }
+    }
+    String::from_utf8_lossy(key).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Coin;
+
+    const LISTED_TOKENS: Map<&str, Coin> = Map::new("listed_tokens");
+
+    fn double_prices(deps: DepsMut) -> StdResult<()> {
+        transform_map(deps.storage, "listed_tokens", |_, price: Coin| {
+            Ok(Some(Coin::new(price.amount.u128() * 2, price.denom)))
+        })?;
+        Ok(())
+    }
+
+    fn drop_config(deps: DepsMut) -> StdResult<()> {
+        deps.storage.remove(b"config");
+        Ok(())
+    }
+
+    const REGISTRY: &[Migration] = &[
+        Migration {
+            from: "0.1.0",
+            to: "0.2.0",
+            description: "double listing prices",
+            migrate: double_prices,
+        },
+        Migration {
+            from: "0.2.0",
+            to: "0.3.0",
+            description: "drop config",
+            migrate: drop_config,
+        },
+    ];
+
+    fn version(version: &str) -> Version {
+        parse_version(version).unwrap()
+    }
+
+    #[test]
+    fn test_plan() {
+        let steps = plan(REGISTRY, &version("0.1.0"), &version("0.3.0")).unwrap();
+        assert_eq!(steps.len(), 2);
+        let steps = plan(REGISTRY, &version("0.2.0"), &version("0.3.0")).unwrap();
+        assert_eq!(steps[0].to, "0.3.0");
+        let steps = plan(REGISTRY, &version("0.1.0"), &version("0.2.0")).unwrap();
+        assert_eq!(steps.len(), 1);
+        // no step upgrades 0.0.1
+        plan(REGISTRY, &version("0.0.1"), &version("0.3.0")).unwrap_err();
+    }
+
+    #[test]
+    fn test_dry_run_and_migrate() {
+        let mut deps = mock_dependencies();
+        LISTED_TOKENS
+            .save(deps.as_mut().storage, "1", &Coin::new(100, "uturnt"))
+            .unwrap();
+        LISTED_TOKENS
+            .save(deps.as_mut().storage, "2", &Coin::new(50, "uturnt"))
+            .unwrap();
+        deps.as_mut().storage.set(b"config", b"{}");
+
+        let report = dry_run(
+            deps.as_ref(),
+            REGISTRY,
+            &version("0.1.0"),
+            &version("0.3.0"),
+        )
+        .unwrap();
+        assert_eq!(report.steps.len(), 2);
+        assert_eq!(
+            report.changes,
+            vec![
+                NamespaceChanges {
+                    namespace: "config".to_string(),
+                    updated: 0,
+                    removed: 1,
+                },
+                NamespaceChanges {
+                    namespace: "listed_tokens".to_string(),
+                    updated: 2,
+                    removed: 0,
+                },
+            ]
+        );
+        // nothing was written
+        assert_eq!(
+            LISTED_TOKENS.load(deps.as_ref().storage, "1").unwrap(),
+            Coin::new(100, "uturnt")
+        );
+
+        migrate(
+            deps.as_mut(),
+            &mock_env(),
+            REGISTRY,
+            &version("0.1.0"),
+            &version("0.3.0"),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            LISTED_TOKENS.load(deps.as_ref().storage, "1").unwrap(),
+            Coin::new(200, "uturnt")
+        );
+        assert_eq!(deps.as_ref().storage.get(b"config"), None);
+    }
+
+    #[test]
+    fn test_transform_item() {
+        let mut deps = mock_dependencies();
+        let metadata: Item<String> = Item::new("metadata");
+        assert!(!transform_item(deps.as_mut().storage, "metadata", |old: String| Ok(old)).unwrap());
+
+        metadata
+            .save(deps.as_mut().storage, &"hub".to_string())
+            .unwrap();
+        transform_item(deps.as_mut().storage, "metadata", |old: String| {
+            Ok(vec![old])
+        })
+        .unwrap();
+        let migrated: Vec<String> = Item::new("metadata").load(deps.as_ref().storage).unwrap();
+        assert_eq!(migrated, vec!["hub"]);
+    }
+}
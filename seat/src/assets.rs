@@ -0,0 +1,65 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_binary, Addr, Api, BankMsg, CosmosMsg, StdResult, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+
+use crate::ContractError;
+
+/// Prefix of the denoms standing for CW20 tokens, e.g. `cw20:<address>`.
+/// Prices and payouts in these denoms are paid with CW20 transfers.
+pub const CW20_PREFIX: &str = "cw20:";
+
+#[cw_serde]
+pub enum PaymentAsset {
+    Native { denom: String },
+    Cw20 { address: Addr },
+}
+
+impl PaymentAsset {
+    pub fn from_denom(denom: &str) -> Self {
+        match denom.strip_prefix(CW20_PREFIX) {
+            Some(address) => PaymentAsset::Cw20 {
+                address: Addr::unchecked(address),
+            },
+            None => PaymentAsset::Native {
+                denom: denom.to_string(),
+            },
+        }
+    }
+}
+
+pub fn cw20_denom(address: &Addr) -> String {
+    format!("{}{}", CW20_PREFIX, address)
+}
+
+/// Errors unless the token of a CW20 denom is a valid address
+pub fn validate_denom(api: &dyn Api, denom: &str) -> StdResult<()> {
+    if let PaymentAsset::Cw20 { address } = PaymentAsset::from_denom(denom) {
+        api.addr_validate(address.as_str())?;
+    }
+    Ok(())
+}
+
+/// Turns a bank send of a CW20 denom into a transfer of the CW20 token
+pub fn into_cosmos_msg(msg: BankMsg) -> Result<CosmosMsg, ContractError> {
+    let BankMsg::Send { to_address, amount } = &msg else {
+        return Ok(CosmosMsg::Bank(msg));
+    };
+    match amount.as_slice() {
+        [coin] => match PaymentAsset::from_denom(&coin.denom) {
+            PaymentAsset::Cw20 { address } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: address.into_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: to_address.clone(),
+                    amount: coin.amount,
+                })?,
+                funds: vec![],
+            })),
+            PaymentAsset::Native { .. } => Ok(CosmosMsg::Bank(msg)),
+        },
+        // CW20 tokens are always sent on their own
+        coins if coins.iter().any(|coin| coin.denom.starts_with(CW20_PREFIX)) => {
+            Err(ContractError::UnsupportedMessage {})
+        }
+        _ => Ok(CosmosMsg::Bank(msg)),
+    }
+}
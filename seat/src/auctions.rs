@@ -0,0 +1,123 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Storage, Timestamp, Uint128};
+use cw_storage_plus::Map;
+
+use crate::state::PAYMENT_DENOMS;
+use crate::ContractError;
+
+#[cw_serde]
+pub enum AuctionKind {
+    /// The price decays linearly from `start_price` at the start of the
+    /// auction to `floor_price` at its end, the first buyer wins the seat
+    Dutch {
+        start_price: Uint128,
+        floor_price: Uint128,
+    },
+    /// Bids are held in escrow until the end of the auction, when the highest
+    /// one wins the seat
+    English {
+        reserve_price: Uint128,
+        min_increment: Uint128,
+    },
+}
+
+#[cw_serde]
+pub struct Bid {
+    pub bidder: Addr,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct Auction {
+    pub seller: Addr,
+    pub denom: String,
+    pub kind: AuctionKind,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    /// Highest bid of an English auction, held in escrow
+    pub highest_bid: Option<Bid>,
+}
+
+/// Running and ended but unsettled auctions, by token id. Seats cannot be
+/// transferred, listed or burned while auctioned.
+pub const AUCTIONS: Map<&str, Auction> = Map::new("auctions");
+
+impl Auction {
+    pub fn new(
+        storage: &dyn Storage,
+        seller: Addr,
+        denom: String,
+        kind: AuctionKind,
+        start: Timestamp,
+        end: Timestamp,
+    ) -> Result<Self, ContractError> {
+        let invalid = |reason: &str| ContractError::InvalidAuction {
+            reason: reason.to_string(),
+        };
+        if !PAYMENT_DENOMS.load(storage)?.contains(&denom) {
+            return Err(invalid("the denom is not a payment denom"));
+        }
+        if end <= start {
+            return Err(invalid("auctions must end after they start"));
+        }
+        match &kind {
+            AuctionKind::Dutch {
+                start_price,
+                floor_price,
+            } => {
+                if start_price.is_zero() || floor_price > start_price {
+                    return Err(invalid(
+                        "the start price must be positive and above the floor price",
+                    ));
+                }
+            }
+            AuctionKind::English { min_increment, .. } => {
+                if min_increment.is_zero() {
+                    return Err(invalid("the minimum bid increment cannot be zero"));
+                }
+            }
+        }
+        Ok(Auction {
+            seller,
+            denom,
+            kind,
+            start,
+            end,
+            highest_bid: None,
+        })
+    }
+
+    pub fn is_open(&self, now: Timestamp) -> bool {
+        self.start <= now && now < self.end
+    }
+
+    /// Price of a Dutch auction at `now`, the lowest acceptable bid of an
+    /// English auction. `None` unless the auction is open.
+    pub fn asking_price(&self, now: Timestamp) -> Option<Coin> {
+        if !self.is_open(now) {
+            return None;
+        }
+        let amount = match &self.kind {
+            AuctionKind::Dutch {
+                start_price,
+                floor_price,
+            } => {
+                let elapsed = now.seconds() - self.start.seconds();
+                let duration = self.end.seconds() - self.start.seconds();
+                let decay = (*start_price - *floor_price).multiply_ratio(elapsed, duration);
+                *start_price - decay
+            }
+            AuctionKind::English {
+                reserve_price,
+                min_increment,
+            } => match &self.highest_bid {
+                Some(bid) => bid.amount + *min_increment,
+                None => *reserve_price,
+            },
+        };
+        Some(Coin {
+            denom: self.denom.clone(),
+            amount,
+        })
+    }
+}
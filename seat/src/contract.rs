@@ -1,21 +1,29 @@
-#[cfg(not(feature = "library"))]
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{entry_point, from_slice, to_vec, CosmosMsg};
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
+use cosmwasm_std::{CosmosMsg, Reply, SubMsg};
 use cw2::set_contract_version;
-use semver::Version;
+use migrations::parse_version;
 
+use crate::assets::into_cosmos_msg;
 use crate::error::ContractError;
+use crate::migrate::MIGRATIONS;
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{Config, SeatModules, CONFIG, HUB_CONTRACT};
+use crate::payments::{FeeConfig, FEE_CONFIG};
+use crate::state::{
+    validate_payment_denoms, Config, SeatModules, CONFIG, HUB_CONTRACT, PAYMENT_DENOMS,
+    TRANSFERABLE,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:seat";
-const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cw_serde]
 pub struct MigrateMsg {
-    pub owner: String,
+    /// Transfers the contract to this address when set
+    pub owner: Option<String>,
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -28,8 +36,20 @@ pub fn instantiate(
     let mut mut_deps = Box::new(deps);
     let hub_contract = mut_deps.branch().api.addr_validate(&msg.hub_contract)?;
     HUB_CONTRACT.save(mut_deps.storage, &hub_contract)?;
+    validate_payment_denoms(mut_deps.api, &msg.payment_denoms)?;
+    PAYMENT_DENOMS.save(mut_deps.storage, &msg.payment_denoms)?;
+    if let Some(transferable) = msg.transferable {
+        TRANSFERABLE.save(mut_deps.storage, &transferable)?;
+    }
+    if let Some(fee) = msg.fee.clone() {
+        let fee_config = FeeConfig::new(mut_deps.api, fee)?;
+        if fee_config.admin == mut_deps.api.addr_validate(&msg.ownable.owner)? {
+            return Err(ContractError::FeeAdminIsOwner {});
+        }
+        FEE_CONFIG.save(mut_deps.storage, &fee_config)?;
+    }
     // instantiate all modules
-    let mut modules = SeatModules::new(mut_deps.branch().as_ref());
+    let mut modules = SeatModules::new(mut_deps.branch().as_ref())?;
     let res = modules.instantiate(mut_deps.branch(), env, info.clone(), &msg);
     set_contract_version(mut_deps.storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
     CONFIG.save(mut_deps.storage, &Config { owner: info.sender })?;
@@ -43,52 +63,93 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    let mut modules = SeatModules::new(deps.as_ref());
-    modules.execute(deps, env, info, msg).map(|response| {
-        let mut res = Response::new();
-        res.attributes = response.attributes;
-        res.data = response.data;
-        res.events = response.events;
-        for message in &response.messages {
-            if let CosmosMsg::Bank(msg) = &message.msg {
-                res = res.add_message(msg.clone());
-            }
-        }
-        res
-    })
+    let mut modules = SeatModules::new(deps.as_ref())?;
+    modules
+        .execute(deps, env, info, msg)
+        .and_then(forward_response)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let mut modules = SeatModules::new(deps.as_ref())?;
+    modules.reply(deps, env, msg).and_then(forward_response)
+}
+
+/// Modules respond with `Binary` custom messages, so their responses are
+/// rebuilt with every sub-message (reply id and gas limit included) converted
+/// into a message the chain can dispatch.
+pub fn forward_response(response: Response<Binary>) -> Result<Response, ContractError> {
+    let mut res = Response::new()
+        .add_attributes(response.attributes)
+        .add_events(response.events);
+    res.data = response.data;
+    for message in response.messages {
+        res = res.add_submessage(SubMsg {
+            id: message.id,
+            msg: forward_msg(message.msg)?,
+            gas_limit: message.gas_limit,
+            reply_on: message.reply_on,
+        });
+    }
+    Ok(res)
+}
+
+fn forward_msg(msg: CosmosMsg<Binary>) -> Result<CosmosMsg, ContractError> {
+    match msg {
+        CosmosMsg::Bank(msg) => into_cosmos_msg(msg),
+        CosmosMsg::Wasm(msg) => Ok(CosmosMsg::Wasm(msg)),
+        CosmosMsg::Staking(msg) => Ok(CosmosMsg::Staking(msg)),
+        CosmosMsg::Distribution(msg) => Ok(CosmosMsg::Distribution(msg)),
+        CosmosMsg::Stargate { type_url, value } => Ok(CosmosMsg::Stargate { type_url, value }),
+        CosmosMsg::Ibc(msg) => Ok(CosmosMsg::Ibc(msg)),
+        CosmosMsg::Gov(msg) => Ok(CosmosMsg::Gov(msg)),
+        // custom messages have no meaning outside of the modules
+        _ => Err(ContractError::UnsupportedMessage {}),
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    let modules = SeatModules::new(deps);
+    let modules = SeatModules::new(deps)?;
     modules.query(deps, env, msg)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     let ver = cw2::get_contract_version(deps.storage)?;
     // ensure we are migrating from an allowed contract
     if ver.contract != CONTRACT_NAME {
         return Err(StdError::generic_err("Can only upgrade from same type").into());
     }
-    let old_contract_ver = Version::parse(&ver.version).unwrap();
-    let new_contract_ver = Version::parse(CONTRACT_VERSION).unwrap();
+    let old_contract_ver = parse_version(&ver.version)?;
+    let new_contract_ver = parse_version(CONTRACT_VERSION)?;
     // ensure we are migrating from an allowed version
     if old_contract_ver.ge(&new_contract_ver) {
         return Err(StdError::generic_err("Cannot upgrade from a newer version").into());
     }
 
-    let data = deps
-        .storage
-        .get(b"config")
-        .ok_or_else(|| StdError::not_found("State"))?;
-    let mut config: Config = from_slice(&data)?;
-    config.owner = deps.api.addr_validate(&msg.owner)?;
-    deps.storage.set(b"config", &to_vec(&config)?);
+    let applied = migrations::migrate(
+        deps.branch(),
+        &env,
+        MIGRATIONS,
+        &old_contract_ver,
+        &new_contract_ver,
+        msg.owner.as_deref(),
+    )?;
+    let applied: Vec<&str> = applied.iter().map(|step| step.to).collect();
+
+    if let Some(owner) = msg.owner {
+        let owner = deps.api.addr_validate(&owner)?;
+        CONFIG.save(deps.storage, &Config { owner })?;
+    }
     //set the new version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    Ok(Response::default())
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION)
+        .add_attribute("migrations", applied.join(",")))
 }
 
 #[cfg(test)]
@@ -96,19 +157,36 @@ mod tests {
     use std::{cell::RefCell, rc::Rc};
 
     use crate::{
-        msg::ExecuteMsg,
-        state::{ImageSettings, SeatBenefits, SeatMetadata, TokenMetadata},
+        assets::PaymentAsset,
+        auctions::AuctionKind,
+        msg::{
+            AllSeatsResponse, AllowlistMsg, AuctionInfo, AuctionsResponse, EligibilityResponse,
+            ExecuteMsg, ListingMsg, OffersResponse, RenewalConfigMsg, RoyaltiesInfoResponse,
+            RoyaltyConfigMsg, SalePhaseMsg, SubscriptionConfigMsg, SubscriptionInfo,
+            SubscriptionsResponse, TierInfo, TiersResponse,
+        },
+        payments::{take_primary_sale_fee, MAX_ROYALTY_PERCENTAGE},
+        phases::SalePhase,
+        state::{ImageSettings, SeatBenefits, SeatMetadata, TokenMetadata, REPLY_ID_RANGE},
+        tiers::Tier,
     };
 
     use super::*;
     use cosmwasm_std::{
         from_binary,
         testing::{mock_dependencies, mock_env, mock_info},
-        Coin, Empty, Timestamp,
+        to_binary, Addr, BankMsg, Coin, ContractResult, Empty, ReplyOn, SubMsgResponse,
+        SubMsgResult, SystemResult, Timestamp, Uint128, WasmMsg, WasmQuery,
+    };
+    use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+    use cw721::{
+        Cw721QueryMsg, NftInfoResponse, NumTokensResponse, OwnerOfResponse, TokensResponse,
+    };
+    use cw721_base::{
+        state::TokenInfo, ExecuteMsg as Cw721BaseExecuteMsg, MintMsg, QueryMsg as Cw721BaseQueryMsg,
     };
-    use cw721::{Cw721QueryMsg, NumTokensResponse, TokensResponse};
-    use cw721_base::{ExecuteMsg as Cw721BaseExecuteMsg, MintMsg, QueryMsg as Cw721BaseQueryMsg};
     use metadata::QueryResp as MetadataQueryResp;
+    use migrations::MigrationReport;
     use redeemable::{
         ExecuteMsg as RedeemableExecuteMsg, QueryMsg as RedeemableQueryMsg,
         QueryResp as RedeemableQueryResp,
@@ -119,6 +197,7 @@ mod tests {
         QueryResp as SellableQueryResp,
     };
     use serde_json::{from_str, json};
+    use sha2::{Digest, Sha256};
 
     const CREATOR: &str = "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z";
     const USER: &str = "burnt188rjfzzrdxlus60zgnrvs4rg0l73hct3mlvdpe";
@@ -126,7 +205,6 @@ mod tests {
     #[test]
     fn test_seat_module_instantiation() {
         let mut deps = mock_dependencies();
-        deps.querier.update_staking("ustake", &[], &[]);
         let metadata_msg = SeatMetadata {
             name: "Kenny's contract".to_string(),
             image_uri: "image".to_owned(),
@@ -160,7 +238,8 @@ mod tests {
                 "tokens": Map::<&str, Coin>::new()
             },
             "sales": {},
-            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z"
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["ustake"]
         })
         .to_string();
         let instantiate_msg: InstantiateMsg = from_str(&msg).unwrap();
@@ -183,18 +262,25 @@ mod tests {
         let query_msg = Cw721BaseQueryMsg::<Cw721QueryMsg>::NumTokens {};
         let res = query(
             deps.as_ref(),
-            env,
+            env.clone(),
             from_str(&json!({ "seat_token": query_msg }).to_string()).unwrap(),
         )
         .unwrap();
         let result: NumTokensResponse = from_binary(&res).unwrap();
         assert_eq!(result.count, 0);
+
+        // the seat contract reports the hub it belongs to
+        let res = query(deps.as_ref(), env, QueryMsg::HubContract {}).unwrap();
+        let hub_contract: Addr = from_binary(&res).unwrap();
+        assert_eq!(
+            hub_contract,
+            "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z"
+        );
     }
 
     #[test]
     fn test_seat_module_tokens() {
         let mut deps = mock_dependencies();
-        deps.querier.update_staking("ustake", &[], &[]);
 
         let metadata_msg = SeatMetadata {
             name: "Kenny's contract".to_string(),
@@ -229,7 +315,8 @@ mod tests {
                 "tokens": {}
             },
             "sales": {},
-            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z"
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["ustake", "uturnt"]
         })
         .to_string();
         let env = mock_env();
@@ -249,6 +336,8 @@ mod tests {
                     description: Some("".to_string()),
                     royalty_percentage: Some(0),
                     royalty_payment_address: Some("".to_string()),
+                    tier: None,
+                    expires_at: None,
                 },
             });
             let mint_msg = json!({ "seat_token": msg }).to_string();
@@ -383,7 +472,6 @@ mod tests {
         use sales::msg::QueryResp;
 
         let mut deps = mock_dependencies();
-        deps.querier.update_staking("ustake", &[], &[]);
 
         let metadata_msg = SeatMetadata {
             name: "Kenny's contract".to_string(),
@@ -418,7 +506,8 @@ mod tests {
                 "tokens": {}
             },
             "sales": {},
-            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z"
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["ustake", "USDC"]
         })
         .to_string();
         let mut env = mock_env();
@@ -464,6 +553,19 @@ mod tests {
         let fake_info = mock_info("hacker", &[]);
         execute(deps.as_mut(), env.clone(), fake_info, execute_msg_1)
             .expect_err("primary sales should not be added");
+        // primary sales are priced in payment denoms
+        let unaccepted = json_exec_msg.replace("USDC", "uatom");
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&unaccepted).unwrap(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::UnsupportedPaymentAsset { denom } if denom == "uatom"
+        ));
         // set block time
         execute(deps.as_mut(), env.clone(), info.clone(), execute_msg_2)
             .expect("primary sales added");
@@ -570,4 +672,3524 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn test_seat_module_redeemable() {
+        let mut deps = mock_dependencies();
+
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![SeatBenefits {
+                name: "name".to_string(),
+                status: "status".to_string(),
+            }],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": {}
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["ustake"]
+        })
+        .to_string();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+        let instantiate_msg: InstantiateMsg = from_str(&msg).unwrap();
+
+        instantiate(deps.as_mut(), env.clone(), info.clone(), instantiate_msg).unwrap();
+
+        // mint a token for the user
+        let msg = Cw721BaseExecuteMsg::<TokenMetadata, Empty>::Mint(MintMsg {
+            token_id: "1".to_string(),
+            owner: USER.to_string(),
+            token_uri: Some("https://example.com".to_string()),
+            extension: TokenMetadata {
+                name: Some("".to_string()),
+                description: Some("".to_string()),
+                royalty_percentage: Some(0),
+                royalty_payment_address: Some("".to_string()),
+                tier: None,
+                expires_at: None,
+            },
+        });
+        let mint_msg = json!({ "seat_token": msg }).to_string();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            from_str(&mint_msg).unwrap(),
+        )
+        .unwrap();
+
+        // only the seat owner can redeem its benefits
+        let msg = RedeemableExecuteMsg::RedeemItem("1".to_string());
+        let redeem_msg = json!({ "redeemable": msg }).to_string();
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            from_str(&redeem_msg).unwrap(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotSeatOwner { .. }));
+
+        // unknown seats cannot be redeemed
+        let msg = RedeemableExecuteMsg::RedeemItem("2".to_string());
+        let missing_msg = json!({ "redeemable": msg }).to_string();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            from_str(&missing_msg).unwrap(),
+        )
+        .expect_err("seat does not exist");
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            from_str(&redeem_msg).unwrap(),
+        )
+        .expect("seat benefits redeemed");
+
+        let query_msg = RedeemableQueryMsg::IsRedeemed("1".to_string());
+        let res = query(
+            deps.as_ref(),
+            env,
+            from_str(&json!({ "redeemable": query_msg }).to_string()).unwrap(),
+        )
+        .unwrap();
+        let result: RedeemableQueryResp = from_binary(&res).unwrap();
+        match result {
+            RedeemableQueryResp::IsRedeemed(redeemed) => assert!(redeemed),
+        }
+    }
+
+    #[test]
+    fn test_forward_response() {
+        let wasm_msg = WasmMsg::Execute {
+            contract_addr: "contract".to_string(),
+            msg: to_binary(&json!({"do": {}})).unwrap(),
+            funds: vec![],
+        };
+        let response = Response::<Binary>::new()
+            .add_attribute("action", "test")
+            .add_message(BankMsg::Send {
+                to_address: USER.to_string(),
+                amount: vec![Coin::new(10, "ustake")],
+            })
+            .add_submessage(SubMsg::reply_on_success(wasm_msg.clone(), 7).with_gas_limit(1000));
+
+        let res = forward_response(response).unwrap();
+        assert_eq!(res.attributes.len(), 1);
+        assert_eq!(res.messages.len(), 2);
+        assert_eq!(res.messages[1].id, 7);
+        assert_eq!(res.messages[1].gas_limit, Some(1000));
+        assert_eq!(res.messages[1].reply_on, ReplyOn::Success);
+        assert_eq!(res.messages[1].msg, CosmosMsg::Wasm(wasm_msg));
+
+        // custom module messages cannot be dispatched
+        let response = Response::<Binary>::new()
+            .add_message(CosmosMsg::Custom(Binary::from(b"custom".to_vec())));
+        let err = forward_response(response).unwrap_err();
+        assert!(matches!(err, ContractError::UnsupportedMessage {}));
+    }
+
+    #[test]
+    fn test_reply() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let msg = Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        };
+        let res = reply(deps.as_mut(), env.clone(), msg).unwrap();
+        assert_eq!(res.attributes[1].value, "1");
+        assert_eq!(res.attributes[2].value, "seat");
+
+        // ids are reserved per module
+        let ok = |id: u64| Reply {
+            id,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        };
+        let res = reply(deps.as_mut(), env.clone(), ok(6 * REPLY_ID_RANGE + 1)).unwrap();
+        assert_eq!(res.attributes[2].value, "sales");
+        let err = reply(deps.as_mut(), env.clone(), ok(7 * REPLY_ID_RANGE)).unwrap_err();
+        assert!(matches!(err, ContractError::UnknownReply { id: 7000 }));
+
+        let msg = Reply {
+            id: 2,
+            result: SubMsgResult::Err("failed".to_string()),
+        };
+        let err = reply(deps.as_mut(), env, msg).unwrap_err();
+        assert!(matches!(err, ContractError::SubMsgFailed { id: 2, .. }));
+    }
+
+    #[test]
+    fn test_all_seats() {
+        let mut deps = mock_dependencies();
+
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![SeatBenefits {
+                name: "name".to_string(),
+                status: "status".to_string(),
+            }],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": {}
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["ustake", "uturnt"]
+        })
+        .to_string();
+        let env = mock_env();
+        let info = mock_info(CREATOR, &[]);
+        let instantiate_msg: InstantiateMsg = from_str(&msg).unwrap();
+        instantiate(deps.as_mut(), env.clone(), info.clone(), instantiate_msg).unwrap();
+
+        for (token_id, owner) in [("1", CREATOR), ("2", CREATOR), ("3", USER)] {
+            let msg = Cw721BaseExecuteMsg::<TokenMetadata, Empty>::Mint(MintMsg {
+                token_id: token_id.to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: TokenMetadata {
+                    name: None,
+                    description: None,
+                    royalty_percentage: None,
+                    royalty_payment_address: None,
+                    tier: None,
+                    expires_at: None,
+                },
+            });
+            let mint_msg = json!({ "seat_token": msg }).to_string();
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                from_str(&mint_msg).unwrap(),
+            )
+            .unwrap();
+        }
+        let msg = SellableExecuteMsg::List {
+            listings: Map::from([
+                ("1".to_string(), Coin::new(200, "uturnt")),
+                ("2".to_string(), Coin::new(100, "uturnt")),
+            ]),
+        };
+        let list_msg = json!({ "sellable": msg }).to_string();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            from_str(&list_msg).unwrap(),
+        )
+        .unwrap();
+
+        let all_seats = |query_msg: serde_json::Value| -> AllSeatsResponse {
+            let msg = from_str(&json!({ "all_seats": query_msg }).to_string()).unwrap();
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap()
+        };
+
+        // paginate through all seats
+        let res = all_seats(json!({ "limit": 2 }));
+        assert_eq!(res.seats.len(), 2);
+        assert_eq!(res.next_start_after, Some("2".to_string()));
+        let res = all_seats(json!({ "start_after": "2", "limit": 2 }));
+        assert_eq!(res.seats.len(), 1);
+        assert_eq!(res.seats[0].token_id, "3");
+        assert_eq!(res.next_start_after, None);
+
+        // filter by owner
+        let res = all_seats(json!({ "owner": USER }));
+        assert_eq!(res.seats.len(), 1);
+        assert_eq!(res.seats[0].token_id, "3");
+
+        // filter by listing and price
+        let res = all_seats(json!({ "listed_only": true }));
+        assert_eq!(res.seats.len(), 2);
+        let res = all_seats(json!({ "min_price": "150" }));
+        assert_eq!(res.seats.len(), 1);
+        assert_eq!(res.seats[0].token_id, "1");
+        assert_eq!(res.seats[0].listed_price, Some(Coin::new(200, "uturnt")));
+        let res = all_seats(json!({ "max_price": "150", "owner": USER }));
+        assert!(res.seats.is_empty());
+        assert_eq!(res.next_start_after, None);
+    }
+
+    #[test]
+    fn test_all_seats_scan_bound() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+
+        // 150 cheap listings sorted before a single expensive one
+        let mut listings = vec![];
+        for i in 0..=150 {
+            let token_id = format!("{:03}", i);
+            let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+                token_id: token_id.clone(),
+                owner: CREATOR.to_string(),
+                token_uri: None,
+                extension: TokenMetadata {
+                    name: None,
+                    description: None,
+                    royalty_percentage: None,
+                    royalty_payment_address: None,
+                    tier: None,
+                    expires_at: None,
+                },
+            }));
+            execute(deps.as_mut(), env.clone(), info.clone(), mint_msg).unwrap();
+            let price = if i == 150 { 200 } else { 100 };
+            listings.push(ListingMsg {
+                token_id,
+                price: Coin::new(price, "uturnt"),
+                expires_at: None,
+            });
+        }
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::ListSeats { listings },
+        )
+        .unwrap();
+
+        let all_seats = |query_msg: serde_json::Value| -> AllSeatsResponse {
+            let msg = from_str(&json!({ "all_seats": query_msg }).to_string()).unwrap();
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap()
+        };
+
+        // a page stops after going through 100 seats, even without a match
+        let res = all_seats(json!({ "min_price": "150" }));
+        assert!(res.seats.is_empty());
+        assert_eq!(res.next_start_after, Some("099".to_string()));
+        let res = all_seats(json!({ "min_price": "150", "start_after": "099" }));
+        assert_eq!(res.seats.len(), 1);
+        assert_eq!(res.seats[0].token_id, "150");
+        assert_eq!(res.next_start_after, None);
+    }
+
+    #[test]
+    fn test_module_query_errors() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        // modules that were never instantiated report an error
+        let msg = json!({"metadata": {"get_metadata": {}}}).to_string();
+        let err = query(deps.as_ref(), env.clone(), from_str(&msg).unwrap()).unwrap_err();
+        match err {
+            StdError::GenericErr { msg } => assert!(msg.starts_with("metadata query failed")),
+            err => panic!("unexpected error: {}", err),
+        }
+
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["ustake"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(deps.as_mut(), env.clone(), info, from_str(&msg).unwrap()).unwrap();
+
+        // querying a token that was never minted
+        let query_msg = Cw721BaseQueryMsg::<Cw721QueryMsg>::OwnerOf {
+            token_id: "missing".to_string(),
+            include_expired: None,
+        };
+        let msg = json!({ "seat_token": query_msg }).to_string();
+        let err = query(deps.as_ref(), env.clone(), from_str(&msg).unwrap()).unwrap_err();
+        match err {
+            StdError::GenericErr { msg } => assert!(msg.starts_with("seat_token query failed")),
+            err => panic!("unexpected error: {}", err),
+        }
+
+        // corrupted payment denoms are reported rather than ignored
+        deps.as_mut().storage.set(b"payment_denoms", b"not json");
+        let msg = json!({"metadata": {"get_metadata": {}}}).to_string();
+        let err = query(deps.as_ref(), env, from_str(&msg).unwrap()).unwrap_err();
+        assert!(matches!(err, StdError::ParseErr { .. }));
+    }
+
+    #[test]
+    fn test_payment_denoms() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::PaymentDenoms {}).unwrap();
+        let denoms: Vec<String> = from_binary(&res).unwrap();
+        assert_eq!(denoms, vec!["uturnt"]);
+
+        // only the owner can change the accepted denoms
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            ExecuteMsg::SetPaymentDenoms {
+                denoms: vec!["uatom".to_string()],
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::SetPaymentDenoms { denoms: vec![] },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidPaymentDenoms { .. }));
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::SetPaymentDenoms {
+                denoms: vec!["uturnt".to_string(), "uatom".to_string()],
+            },
+        )
+        .unwrap();
+        let res = query(deps.as_ref(), env, QueryMsg::PaymentDenoms {}).unwrap();
+        let denoms: Vec<String> = from_binary(&res).unwrap();
+        assert_eq!(denoms, vec!["uturnt", "uatom"]);
+    }
+
+    #[test]
+    fn test_migrate() {
+        let mut deps = mock_dependencies();
+        deps.querier.update_staking("ustake", &[], &[]);
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.1.0").unwrap();
+        CONFIG
+            .save(
+                deps.as_mut().storage,
+                &Config {
+                    owner: Addr::unchecked(CREATOR),
+                },
+            )
+            .unwrap();
+        // a seat above the royalty cap, listed in a denom no longer accepted,
+        // and the listing of a burned seat
+        let tokens = cw_storage_plus::Map::<&str, TokenInfo<TokenMetadata>>::new("tokens");
+        let seat = TokenInfo {
+            owner: Addr::unchecked(USER),
+            approvals: vec![],
+            token_uri: None,
+            extension: TokenMetadata {
+                description: None,
+                name: None,
+                royalty_percentage: Some(50),
+                royalty_payment_address: Some(CREATOR.to_string()),
+                tier: None,
+                expires_at: None,
+            },
+        };
+        tokens.save(deps.as_mut().storage, "1", &seat).unwrap();
+        let listings = cw_storage_plus::Map::<&str, Coin>::new("listed_tokens");
+        listings
+            .save(deps.as_mut().storage, "1", &Coin::new(100, "uatom"))
+            .unwrap();
+        listings
+            .save(deps.as_mut().storage, "2", &Coin::new(100, "ustake"))
+            .unwrap();
+
+        // a dry run reports the changes without storing the denoms
+        let msg = QueryMsg::DryRunMigration { from_version: None };
+        let report: MigrationReport =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(report.steps.len(), 2);
+        let namespaces: Vec<&str> = report
+            .changes
+            .iter()
+            .map(|changes| changes.namespace.as_str())
+            .collect();
+        assert_eq!(
+            namespaces,
+            vec!["listed_tokens", "payment_denoms", "tokens", "transferable"]
+        );
+        assert_eq!(report.changes[0].removed, 2);
+        query(deps.as_ref(), mock_env(), QueryMsg::PaymentDenoms {}).unwrap_err();
+
+        migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                owner: Some(USER.to_string()),
+            },
+        )
+        .unwrap();
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::PaymentDenoms {}).unwrap();
+        let denoms: Vec<String> = from_binary(&res).unwrap();
+        assert_eq!(denoms, vec!["ustake"]);
+
+        // the royalty is capped and the stale listings are dropped
+        let seat = tokens.load(deps.as_ref().storage, "1").unwrap();
+        assert_eq!(
+            seat.extension.royalty_percentage,
+            Some(MAX_ROYALTY_PERCENTAGE)
+        );
+        assert!(listings.is_empty(deps.as_ref().storage));
+        assert!(TRANSFERABLE.load(deps.as_ref().storage).unwrap());
+
+        // the ownable module records the new owner
+        let msg = json!({"ownable": {"is_owner": USER}}).to_string();
+        let res = query(deps.as_ref(), mock_env(), from_str(&msg).unwrap()).unwrap();
+        match from_binary(&res).unwrap() {
+            ownable::QueryResp::IsOwner(is_owner) => assert!(is_owner),
+        }
+
+        // without an owner, migrating keeps the current one
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.2.0").unwrap();
+        migrate(deps.as_mut(), mock_env(), MigrateMsg { owner: None }).unwrap();
+        let res = query(deps.as_ref(), mock_env(), from_str(&msg).unwrap()).unwrap();
+        match from_binary(&res).unwrap() {
+            ownable::QueryResp::IsOwner(is_owner) => assert!(is_owner),
+        }
+        assert_eq!(
+            CONFIG.load(deps.as_ref().storage).unwrap().owner,
+            Addr::unchecked(USER)
+        );
+    }
+
+    #[test]
+    fn test_tiers() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["ustake"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+
+        let gold = TierInfo {
+            name: "Gold".to_string(),
+            image_uri: "gold image".to_string(),
+            benefits: vec![SeatBenefits {
+                name: "backstage".to_string(),
+                status: "active".to_string(),
+            }],
+            max_supply: Some(1),
+            transferable: None,
+        };
+        // only the owner manages tiers
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            ExecuteMsg::SetTier {
+                tier_id: "gold".to_string(),
+                tier: gold.clone(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::SetTier {
+                tier_id: "gold".to_string(),
+                tier: gold.clone(),
+            },
+        )
+        .unwrap();
+
+        let mint = |token_id: &str, tier: &str| {
+            ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+                token_id: token_id.to_string(),
+                owner: USER.to_string(),
+                token_uri: None,
+                extension: TokenMetadata {
+                    name: None,
+                    description: None,
+                    royalty_percentage: None,
+                    royalty_payment_address: None,
+                    tier: Some(tier.to_string()),
+                    expires_at: None,
+                },
+            }))
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), mint("1", "gold")).unwrap();
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Tier {
+                tier_id: "gold".to_string(),
+            },
+        )
+        .unwrap();
+        let tier: Tier = from_binary(&res).unwrap();
+        assert_eq!(tier.minted, 1);
+        assert_eq!(tier.benefits, gold.benefits);
+
+        // the tier supply is enforced
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), mint("2", "gold")).unwrap_err();
+        assert!(matches!(err, ContractError::TierSoldOut { .. }));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            mint("2", "silver"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TierNotFound { .. }));
+
+        // minted seats pin the tier
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::SetTier {
+                tier_id: "gold".to_string(),
+                tier: TierInfo {
+                    max_supply: Some(0),
+                    ..gold
+                },
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidTier { .. }));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::RemoveTier {
+                tier_id: "gold".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidTier { .. }));
+
+        let res = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::Tiers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let tiers: TiersResponse = from_binary(&res).unwrap();
+        assert_eq!(tiers.tiers.len(), 1);
+        assert_eq!(tiers.tiers[0].tier_id, "gold");
+    }
+
+    #[test]
+    fn test_royalties() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+
+        let mint = |royalty_percentage: u64, royalty_payment_address: Option<&str>| {
+            ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+                token_id: "1".to_string(),
+                owner: CREATOR.to_string(),
+                token_uri: None,
+                extension: TokenMetadata {
+                    name: None,
+                    description: None,
+                    royalty_percentage: Some(royalty_percentage),
+                    royalty_payment_address: royalty_payment_address.map(str::to_string),
+                    tier: None,
+                    expires_at: None,
+                },
+            }))
+        };
+        // royalties are bounded and paid to a valid address
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            mint(26, Some("royalties")),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidRoyalty { .. }));
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), mint(10, None)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidRoyalty { .. }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            mint(10, Some("Not Valid")),
+        )
+        .unwrap_err();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            mint(10, Some("royalties")),
+        )
+        .unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::RoyaltyInfo {
+                token_id: "1".to_string(),
+                sale_price: Uint128::new(1000),
+            },
+        )
+        .unwrap();
+        let royalty: RoyaltiesInfoResponse = from_binary(&res).unwrap();
+        assert_eq!(royalty.address, "royalties");
+        assert_eq!(royalty.royalty_amount, Uint128::new(100));
+
+        let list_msg = SellableExecuteMsg::List {
+            listings: Map::from([("1".to_string(), Coin::new(1000, "uturnt"))]),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            from_str(&json!({ "sellable": list_msg }).to_string()).unwrap(),
+        )
+        .unwrap();
+
+        let buy_msg = || {
+            from_str::<ExecuteMsg>(
+                &json!({"sellable": {"buy_token": {"token_id": "1"}}}).to_string(),
+            )
+            .unwrap()
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("buyer", &[Coin::new(900, "uturnt")]),
+            buy_msg(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InsufficientFunds { .. }));
+
+        // the price is split between the seller and the royalty address
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("buyer", &[Coin::new(1000, "uturnt")]),
+            buy_msg(),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: CREATOR.to_string(),
+                    amount: vec![Coin::new(900, "uturnt")],
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: "royalties".to_string(),
+                    amount: vec![Coin::new(100, "uturnt")],
+                }),
+            ]
+        );
+
+        let query_msg = Cw721BaseQueryMsg::<Cw721QueryMsg>::OwnerOf {
+            token_id: "1".to_string(),
+            include_expired: None,
+        };
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            from_str(&json!({ "seat_token": query_msg }).to_string()).unwrap(),
+        )
+        .unwrap();
+        let owner: OwnerOfResponse = from_binary(&res).unwrap();
+        assert_eq!(owner.owner, "buyer");
+
+        // the seat is no longer listed
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("buyer", &[Coin::new(1000, "uturnt")]),
+            buy_msg(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TokenNotListed { .. }));
+    }
+
+    #[test]
+    fn test_fees() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt"],
+            "fee": {"admin": "platform", "fee_bps": 500, "collector": "collector"}
+        });
+        let info = mock_info(CREATOR, &[]);
+        // the owner cannot administer the fee
+        let mut owner_admin = msg.clone();
+        owner_admin["fee"]["admin"] = json!(CREATOR);
+        let err = instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&owner_admin.to_string()).unwrap(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::FeeAdminIsOwner {}));
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg.to_string()).unwrap(),
+        )
+        .unwrap();
+
+        // the owner cannot change the fee, only the fee admin can
+        let update_fee = || ExecuteMsg::UpdateFeeConfig {
+            admin: None,
+            fee_bps: Some(1000),
+            collector: None,
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), update_fee()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("platform", &[]),
+            ExecuteMsg::UpdateFeeConfig {
+                admin: None,
+                fee_bps: Some(1001),
+                collector: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidFee { .. }));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("platform", &[]),
+            ExecuteMsg::UpdateFeeConfig {
+                admin: Some(CREATOR.to_string()),
+                fee_bps: None,
+                collector: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::FeeAdminIsOwner {}));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::Ownable(ownable::ExecuteMsg::SetOwner("platform".to_string())),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::FeeAdminIsOwner {}));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("platform", &[]),
+            update_fee(),
+        )
+        .unwrap();
+
+        // secondary sale of a seat with a 10% royalty
+        let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+            token_id: "1".to_string(),
+            owner: CREATOR.to_string(),
+            token_uri: None,
+            extension: TokenMetadata {
+                name: None,
+                description: None,
+                royalty_percentage: Some(10),
+                royalty_payment_address: Some("royalties".to_string()),
+                tier: None,
+                expires_at: None,
+            },
+        }));
+        execute(deps.as_mut(), env.clone(), info.clone(), mint_msg).unwrap();
+        let list_msg = SellableExecuteMsg::List {
+            listings: Map::from([("1".to_string(), Coin::new(1000, "uturnt"))]),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            from_str(&json!({ "sellable": list_msg }).to_string()).unwrap(),
+        )
+        .unwrap();
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("buyer", &[Coin::new(1000, "uturnt")]),
+            from_str(&json!({"sellable": {"buy_token": {"token_id": "1"}}}).to_string()).unwrap(),
+        )
+        .unwrap();
+        let payouts: Vec<(String, Vec<Coin>)> = res
+            .messages
+            .into_iter()
+            .map(|sub_msg| match sub_msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => (to_address, amount),
+                msg => panic!("unexpected message: {:?}", msg),
+            })
+            .collect();
+        assert_eq!(
+            payouts,
+            vec![
+                (CREATOR.to_string(), vec![Coin::new(800, "uturnt")]),
+                ("royalties".to_string(), vec![Coin::new(100, "uturnt")]),
+                ("collector".to_string(), vec![Coin::new(100, "uturnt")]),
+            ]
+        );
+        let attribute = |key: &str| {
+            res.attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.clone())
+        };
+        assert_eq!(attribute("fee"), Some("100uturnt".to_string()));
+        assert_eq!(attribute("royalty"), Some("100uturnt".to_string()));
+        assert_eq!(attribute("seller_proceeds"), Some("800uturnt".to_string()));
+
+        // primary sale payouts are charged the fee as well
+        let res = take_primary_sale_fee(
+            deps.as_ref().storage,
+            Response::new().add_message(BankMsg::Send {
+                to_address: CREATOR.to_string(),
+                amount: vec![Coin::new(50, "usdc")],
+            }),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: CREATOR.to_string(),
+                    amount: vec![Coin::new(45, "usdc")],
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: "collector".to_string(),
+                    amount: vec![Coin::new(5, "usdc")],
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_soulbound_seats() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        // the hub reports "hubowner" as its owner
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { msg, .. } => {
+                let msg: serde_json::Value = from_binary(msg).unwrap();
+                let is_owner = msg["ownable"]["is_owner"] == "hubowner";
+                SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&ownable::QueryResp::IsOwner(is_owner)).unwrap(),
+                ))
+            }
+            _ => unreachable!(),
+        });
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt"],
+            "transferable": false
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+
+        // seats of the "open" tier stay transferable
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::SetTier {
+                tier_id: "open".to_string(),
+                tier: TierInfo {
+                    name: "Open".to_string(),
+                    image_uri: "image".to_string(),
+                    benefits: vec![],
+                    max_supply: None,
+                    transferable: Some(true),
+                },
+            },
+        )
+        .unwrap();
+        for (token_id, tier) in [("1", None), ("2", Some("open".to_string()))] {
+            let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+                token_id: token_id.to_string(),
+                owner: USER.to_string(),
+                token_uri: None,
+                extension: TokenMetadata {
+                    name: None,
+                    description: None,
+                    royalty_percentage: None,
+                    royalty_payment_address: None,
+                    tier,
+                    expires_at: None,
+                },
+            }));
+            execute(deps.as_mut(), env.clone(), info.clone(), mint_msg).unwrap();
+        }
+
+        let user_info = mock_info(USER, &[]);
+        let transfer = |token_id: &str| {
+            ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::TransferNft {
+                recipient: "friend".to_string(),
+                token_id: token_id.to_string(),
+            })
+        };
+        let err =
+            execute(deps.as_mut(), env.clone(), user_info.clone(), transfer("1")).unwrap_err();
+        assert!(matches!(err, ContractError::NonTransferable { .. }));
+        let list_msg = SellableExecuteMsg::List {
+            listings: Map::from([("1".to_string(), Coin::new(1000, "uturnt"))]),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            user_info.clone(),
+            from_str(&json!({ "sellable": list_msg }).to_string()).unwrap(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NonTransferable { .. }));
+        execute(deps.as_mut(), env.clone(), user_info, transfer("2")).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::IsTransferable {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+        assert!(!from_binary::<bool>(&res).unwrap());
+
+        // only the hub owner can revoke a seat
+        let revoke = || ExecuteMsg::RevokeSeat {
+            token_id: "1".to_string(),
+        };
+        let err = execute(deps.as_mut(), env.clone(), info, revoke()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("hubowner", &[]),
+            revoke(),
+        )
+        .unwrap();
+
+        let query_msg = Cw721BaseQueryMsg::<Cw721QueryMsg>::NumTokens {};
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            from_str(&json!({ "seat_token": query_msg }).to_string()).unwrap(),
+        )
+        .unwrap();
+        let result: NumTokensResponse = from_binary(&res).unwrap();
+        assert_eq!(result.count, 1);
+
+        // seat owners can still burn their soulbound seats
+        let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+            token_id: "3".to_string(),
+            owner: USER.to_string(),
+            token_uri: None,
+            extension: TokenMetadata {
+                name: None,
+                description: None,
+                royalty_percentage: None,
+                royalty_payment_address: None,
+                tier: None,
+                expires_at: None,
+            },
+        }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            mint_msg,
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Burn {
+                token_id: "3".to_string(),
+            }),
+        )
+        .unwrap();
+
+        // making a tier soulbound withdraws its listed and auctioned seats
+        let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+            token_id: "4".to_string(),
+            owner: USER.to_string(),
+            token_uri: None,
+            extension: TokenMetadata {
+                name: None,
+                description: None,
+                royalty_percentage: None,
+                royalty_payment_address: None,
+                tier: Some("open".to_string()),
+                expires_at: None,
+            },
+        }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            mint_msg,
+        )
+        .unwrap();
+        let list_msg = ExecuteMsg::ListSeats {
+            listings: vec![ListingMsg {
+                token_id: "2".to_string(),
+                price: Coin::new(1000, "uturnt"),
+                expires_at: None,
+            }],
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("friend", &[]),
+            list_msg,
+        )
+        .unwrap();
+        let start_auction = ExecuteMsg::StartAuction {
+            token_id: "4".to_string(),
+            denom: "uturnt".to_string(),
+            kind: AuctionKind::English {
+                reserve_price: Uint128::new(100),
+                min_increment: Uint128::new(10),
+            },
+            start: None,
+            end: env.block.time.plus_seconds(100),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            start_auction,
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bidder", &[Coin::new(100, "uturnt")]),
+            ExecuteMsg::BidAuction {
+                token_id: "4".to_string(),
+            },
+        )
+        .unwrap();
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            ExecuteMsg::SetTier {
+                tier_id: "open".to_string(),
+                tier: TierInfo {
+                    name: "Open".to_string(),
+                    image_uri: "image".to_string(),
+                    benefits: vec![],
+                    max_supply: None,
+                    transferable: Some(false),
+                },
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: "bidder".to_string(),
+                amount: vec![Coin::new(100, "uturnt")],
+            })]
+        );
+        let attribute = |key: &str| {
+            res.attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.clone())
+        };
+        assert_eq!(attribute("delisted"), Some("1".to_string()));
+        assert_eq!(attribute("cancelled_auctions"), Some("1".to_string()));
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("buyer", &[Coin::new(1000, "uturnt")]),
+            from_str(&json!({"sellable": {"buy_token": {"token_id": "2"}}}).to_string()).unwrap(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TokenNotListed { .. }));
+    }
+
+    #[test]
+    fn test_seat_renewals() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+
+        // seat "1" expires in 100 seconds, seat "2" never does
+        let expiry = env.block.time.plus_seconds(100);
+        for (token_id, expires_at) in [("1", Some(expiry)), ("2", None)] {
+            let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+                token_id: token_id.to_string(),
+                owner: USER.to_string(),
+                token_uri: None,
+                extension: TokenMetadata {
+                    name: None,
+                    description: None,
+                    royalty_percentage: None,
+                    royalty_payment_address: None,
+                    tier: None,
+                    expires_at,
+                },
+            }));
+            execute(deps.as_mut(), env.clone(), info.clone(), mint_msg).unwrap();
+        }
+        let is_active = |deps: Deps, env: Env, token_id: &str| -> bool {
+            let query_msg = QueryMsg::IsActive {
+                token_id: token_id.to_string(),
+            };
+            from_binary(&query(deps, env, query_msg).unwrap()).unwrap()
+        };
+        assert!(is_active(deps.as_ref(), env.clone(), "1"));
+
+        let renew = |token_id: &str| ExecuteMsg::RenewSeat {
+            token_id: token_id.to_string(),
+        };
+        let payment = mock_info(USER, &[Coin::new(100, "uturnt")]);
+        let err = execute(deps.as_mut(), env.clone(), payment.clone(), renew("1")).unwrap_err();
+        assert!(matches!(err, ContractError::NotRenewable { .. }));
+
+        // only the owner configures renewals, priced in a payment denom
+        let set_renewal = |denom: &str| ExecuteMsg::SetRenewalConfig {
+            config: Some(RenewalConfigMsg {
+                price: Coin::new(100, denom),
+                duration: 1000,
+                recipient: CREATOR.to_string(),
+            }),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            set_renewal("uturnt"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            set_renewal("usdc"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidRenewal { .. }));
+        execute(deps.as_mut(), env.clone(), info, set_renewal("uturnt")).unwrap();
+
+        // perpetual seats cannot be renewed
+        let err = execute(deps.as_mut(), env.clone(), payment.clone(), renew("2")).unwrap_err();
+        assert!(matches!(err, ContractError::NotRenewable { .. }));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[Coin::new(50, "uturnt")]),
+            renew("1"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InsufficientFunds { .. }));
+
+        // renewing an active seat extends its current expiry
+        let res = execute(deps.as_mut(), env.clone(), payment.clone(), renew("1")).unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: CREATOR.to_string(),
+                amount: vec![Coin::new(100, "uturnt")],
+            })]
+        );
+        let expiry = expiry.plus_seconds(1000);
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "expires_at" && attr.value == expiry.seconds().to_string()));
+
+        // once expired the seat is inactive and its benefits cannot be redeemed
+        let mut later = env.clone();
+        later.block.time = expiry.plus_seconds(1);
+        assert!(!is_active(deps.as_ref(), later.clone(), "1"));
+        assert!(is_active(deps.as_ref(), later.clone(), "2"));
+        let res = query(
+            deps.as_ref(),
+            later.clone(),
+            QueryMsg::AllSeats {
+                start_after: None,
+                limit: None,
+                owner: None,
+                listed_only: None,
+                min_price: None,
+                max_price: None,
+            },
+        )
+        .unwrap();
+        let res: AllSeatsResponse = from_binary(&res).unwrap();
+        let active: Vec<bool> = res.seats.iter().map(|seat| seat.active).collect();
+        assert_eq!(active, vec![false, true]);
+        let redeem_msg = json!({ "redeemable": RedeemableExecuteMsg::RedeemItem("1".to_string()) });
+        let err = execute(
+            deps.as_mut(),
+            later.clone(),
+            mock_info(USER, &[]),
+            from_str(&redeem_msg.to_string()).unwrap(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::SeatExpired { .. }));
+
+        // renewing an expired seat starts from now
+        let res = execute(deps.as_mut(), later.clone(), payment, renew("1")).unwrap();
+        let expiry = later.block.time.plus_seconds(1000);
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "expires_at" && attr.value == expiry.seconds().to_string()));
+        assert!(is_active(deps.as_ref(), later, "1"));
+    }
+
+    #[test]
+    fn test_subscriptions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+
+        let subscribe = |token_id: &str, periods: u32| ExecuteMsg::Subscribe {
+            token_id: token_id.to_string(),
+            periods,
+        };
+        let pay = |amount: u128| mock_info(USER, &[Coin::new(amount, "uturnt")]);
+        let err = execute(deps.as_mut(), env.clone(), pay(10), subscribe("1", 1)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSubscription { .. }));
+
+        // a period lasts 100 seconds at 10uturnt, followed by 50 seconds of grace
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::SetSubscriptionConfig {
+                config: Some(SubscriptionConfigMsg {
+                    price: Coin::new(10, "uturnt"),
+                    period: 100,
+                    grace_period: 50,
+                    recipient: CREATOR.to_string(),
+                    tier: None,
+                }),
+            },
+        )
+        .unwrap();
+        let err = execute(deps.as_mut(), env.clone(), pay(10), subscribe("1", 0)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSubscription { .. }));
+        let err = execute(deps.as_mut(), env.clone(), pay(10), subscribe("1", 3)).unwrap_err();
+        assert!(matches!(err, ContractError::InsufficientFunds { .. }));
+
+        // the first payment mints the seat to the subscriber
+        let res = execute(deps.as_mut(), env.clone(), pay(30), subscribe("1", 3)).unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: CREATOR.to_string(),
+                amount: vec![Coin::new(30, "uturnt")],
+            })]
+        );
+        let subscription = |deps: Deps, token_id: &str| -> SubscriptionInfo {
+            let query_msg = QueryMsg::Subscription {
+                token_id: token_id.to_string(),
+            };
+            from_binary(&query(deps, mock_env(), query_msg).unwrap()).unwrap()
+        };
+        let now = env.block.time;
+        assert_eq!(
+            subscription(deps.as_ref(), "1"),
+            SubscriptionInfo {
+                token_id: "1".to_string(),
+                owner: Addr::unchecked(USER),
+                paid_until: now.plus_seconds(300),
+                expires_at: now.plus_seconds(350),
+            }
+        );
+        execute(deps.as_mut(), env.clone(), pay(10), subscribe("2", 1)).unwrap();
+
+        let expiring = |deps: Deps, to: u64, start_after: Option<&str>| -> Vec<String> {
+            let query_msg = QueryMsg::ExpiringSubscriptions {
+                from: now,
+                to: now.plus_seconds(to),
+                start_after: start_after.map(str::to_string),
+                limit: None,
+            };
+            let res: SubscriptionsResponse =
+                from_binary(&query(deps, mock_env(), query_msg).unwrap()).unwrap();
+            res.subscriptions
+                .into_iter()
+                .map(|subscription| subscription.token_id)
+                .collect()
+        };
+        assert_eq!(expiring(deps.as_ref(), 150, None), vec!["2"]);
+        assert_eq!(expiring(deps.as_ref(), 300, None), vec!["2", "1"]);
+        assert_eq!(expiring(deps.as_ref(), 300, Some("2")), vec!["1"]);
+
+        // paying during the grace period resumes from the end of the paid period
+        let mut later = env.clone();
+        later.block.time = now.plus_seconds(320);
+        execute(deps.as_mut(), later.clone(), pay(10), subscribe("1", 1)).unwrap();
+        assert_eq!(
+            subscription(deps.as_ref(), "1").paid_until,
+            now.plus_seconds(400)
+        );
+
+        // lapsed subscriptions are inactive and start over from now
+        later.block.time = now.plus_seconds(451);
+        let query_msg = QueryMsg::IsActive {
+            token_id: "1".to_string(),
+        };
+        let res = query(deps.as_ref(), later.clone(), query_msg).unwrap();
+        assert!(!from_binary::<bool>(&res).unwrap());
+        execute(deps.as_mut(), later.clone(), pay(10), subscribe("1", 1)).unwrap();
+        assert_eq!(
+            subscription(deps.as_ref(), "1").paid_until,
+            now.plus_seconds(551)
+        );
+        assert_eq!(expiring(deps.as_ref(), 300, None), vec!["2"]);
+
+        // subscriptions are not renewed as regular seats, and regular seats
+        // cannot be subscribed to
+        let err = execute(
+            deps.as_mut(),
+            later.clone(),
+            pay(10),
+            ExecuteMsg::RenewSeat {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotRenewable { .. }));
+        let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+            token_id: "3".to_string(),
+            owner: USER.to_string(),
+            token_uri: None,
+            extension: TokenMetadata {
+                name: None,
+                description: None,
+                royalty_percentage: None,
+                royalty_payment_address: None,
+                tier: None,
+                expires_at: None,
+            },
+        }));
+        execute(deps.as_mut(), later.clone(), info, mint_msg).unwrap();
+        let err = execute(deps.as_mut(), later, pay(10), subscribe("3", 1)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSubscription { .. }));
+    }
+
+    #[test]
+    fn test_sale_phases() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["ustake", "USDC"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+        let primary_sale = json!({
+            "sales": {
+                "primary_sale": {
+                    "total_supply": "10",
+                    "start_time": "1674567586",
+                    "end_time": "1675567587",
+                    "price": [{
+                        "denom": "USDC",
+                        "amount": "10"
+                    }]
+                }
+            }
+        });
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&primary_sale.to_string()).unwrap(),
+        )
+        .unwrap();
+
+        // "alice" and "bob" are the leaves of the Merkle allowlist
+        let leaf = |address: &str| -> [u8; 32] { Sha256::digest(address.as_bytes()).into() };
+        let (alice, bob) = (leaf("alice"), leaf("bob"));
+        let root: [u8; 32] = Sha256::new()
+            .chain_update(alice.min(bob))
+            .chain_update(alice.max(bob))
+            .finalize()
+            .into();
+
+        // an allowlist presale, a Merkle presale then a public sale
+        let now = env.block.time;
+        let phases = vec![
+            SalePhaseMsg {
+                id: "presale".to_string(),
+                start: now,
+                end: Some(now.plus_seconds(100)),
+                allowlist: Some(AllowlistMsg::Addresses(vec![USER.to_string()])),
+                mint_limit: Some(1),
+            },
+            SalePhaseMsg {
+                id: "merkle".to_string(),
+                start: now.plus_seconds(100),
+                end: Some(now.plus_seconds(200)),
+                allowlist: Some(AllowlistMsg::MerkleRoot(hex::encode(root))),
+                mint_limit: None,
+            },
+            SalePhaseMsg {
+                id: "public".to_string(),
+                start: now.plus_seconds(200),
+                end: None,
+                allowlist: None,
+                mint_limit: None,
+            },
+        ];
+        let set_phases = |phases: Vec<SalePhaseMsg>| ExecuteMsg::SetSalePhases { phases };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            set_phases(phases.clone()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let overlapping = vec![phases[1].clone(), phases[0].clone()];
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            set_phases(overlapping),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSalePhase { .. }));
+        execute(deps.as_mut(), env.clone(), info, set_phases(phases.clone())).unwrap();
+
+        let buy = |token_id: &str| -> ExecuteMsg {
+            let msg = json!({
+                "sales": {
+                    "buy_item": {
+                        "token_id": token_id,
+                        "owner": USER,
+                        "token_uri": null,
+                        "extension": {}
+                    }
+                }
+            });
+            from_str(&msg.to_string()).unwrap()
+        };
+        let buyer = |address: &str| mock_info(address, &[Coin::new(10, "USDC")]);
+        let eligibility = |deps: Deps, env: Env, address: &str| -> EligibilityResponse {
+            let query_msg = QueryMsg::Eligibility {
+                address: address.to_string(),
+                proof: None,
+            };
+            from_binary(&query(deps, env, query_msg).unwrap()).unwrap()
+        };
+
+        // nothing can be bought before the first phase
+        let mut before = env.clone();
+        before.block.time = now.minus_seconds(1);
+        let err = execute(deps.as_mut(), before, buyer(USER), buy("1")).unwrap_err();
+        assert!(matches!(err, ContractError::SaleNotActive {}));
+
+        // only allowlisted addresses buy during the presale, up to its limit
+        assert_eq!(
+            eligibility(deps.as_ref(), env.clone(), USER),
+            EligibilityResponse {
+                phase_id: Some("presale".to_string()),
+                eligible: true,
+                minted: 0,
+                mint_limit: Some(1),
+            }
+        );
+        assert!(!eligibility(deps.as_ref(), env.clone(), "stranger").eligible);
+        let err = execute(deps.as_mut(), env.clone(), buyer("stranger"), buy("1")).unwrap_err();
+        assert!(matches!(err, ContractError::NotAllowlisted { .. }));
+        execute(deps.as_mut(), env.clone(), buyer(USER), buy("1")).unwrap();
+        let err = execute(deps.as_mut(), env.clone(), buyer(USER), buy("2")).unwrap_err();
+        assert!(matches!(err, ContractError::PhaseMintLimitReached { .. }));
+        assert!(!eligibility(deps.as_ref(), env.clone(), USER).eligible);
+
+        // the Merkle presale requires a proof of the buyer's leaf
+        let mut merkle = env.clone();
+        merkle.block.time = now.plus_seconds(150);
+        let buy_allowlisted = |token_id: &str, proof: [u8; 32]| ExecuteMsg::BuyAllowlisted {
+            proof: vec![hex::encode(proof)],
+            mint_msg: MintMsg {
+                token_id: token_id.to_string(),
+                owner: USER.to_string(),
+                token_uri: None,
+                extension: TokenMetadata {
+                    name: None,
+                    description: None,
+                    royalty_percentage: None,
+                    royalty_payment_address: None,
+                    tier: None,
+                    expires_at: None,
+                },
+            },
+        };
+        let err = execute(deps.as_mut(), merkle.clone(), buyer("alice"), buy("2")).unwrap_err();
+        assert!(matches!(err, ContractError::NotAllowlisted { .. }));
+        let err = execute(
+            deps.as_mut(),
+            merkle.clone(),
+            buyer("bob"),
+            buy_allowlisted("2", bob),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotAllowlisted { .. }));
+        execute(
+            deps.as_mut(),
+            merkle.clone(),
+            buyer("alice"),
+            buy_allowlisted("2", bob),
+        )
+        .unwrap();
+        let query_msg = QueryMsg::Eligibility {
+            address: "bob".to_string(),
+            proof: Some(vec![hex::encode(alice)]),
+        };
+        let res: EligibilityResponse =
+            from_binary(&query(deps.as_ref(), merkle, query_msg).unwrap()).unwrap();
+        assert!(res.eligible);
+
+        // anyone buys during the public sale
+        let mut public = env.clone();
+        public.block.time = now.plus_seconds(250);
+        execute(deps.as_mut(), public.clone(), buyer("stranger"), buy("3")).unwrap();
+        let res = query(deps.as_ref(), public, QueryMsg::ActiveSalePhase {}).unwrap();
+        let phase: Option<SalePhase> = from_binary(&res).unwrap();
+        assert_eq!(phase.unwrap().id, "public");
+
+        // a new schedule reusing a phase id starts with no mints
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            set_phases(vec![phases[0].clone()]),
+        )
+        .unwrap();
+        assert_eq!(eligibility(deps.as_ref(), env, USER).minted, 0);
+    }
+
+    #[test]
+    fn test_primary_sale_seats() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["ustake", "USDC"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+        let primary_sale = json!({
+            "sales": {
+                "primary_sale": {
+                    "total_supply": "10",
+                    "start_time": "1674567586",
+                    "end_time": "1675567587",
+                    "price": [{
+                        "denom": "USDC",
+                        "amount": "10"
+                    }]
+                }
+            }
+        });
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&primary_sale.to_string()).unwrap(),
+        )
+        .unwrap();
+        for tier_id in ["silver", "gold"] {
+            let tier = TierInfo {
+                name: tier_id.to_string(),
+                image_uri: "image".to_string(),
+                benefits: vec![],
+                max_supply: None,
+                transferable: None,
+            };
+            let set_tier = ExecuteMsg::SetTier {
+                tier_id: tier_id.to_string(),
+                tier,
+            };
+            execute(deps.as_mut(), env.clone(), info.clone(), set_tier).unwrap();
+        }
+
+        let buy = |token_id: &str, extension: serde_json::Value| -> ExecuteMsg {
+            let msg = json!({
+                "sales": {
+                    "buy_item": {
+                        "token_id": token_id,
+                        "owner": USER,
+                        "token_uri": null,
+                        "extension": extension
+                    }
+                }
+            });
+            from_str(&msg.to_string()).unwrap()
+        };
+        let buyer = mock_info(USER, &[Coin::new(10, "USDC")]);
+        let seat = |deps: Deps, token_id: &str| -> TokenMetadata {
+            let query_msg = QueryMsg::SeatToken(Cw721BaseQueryMsg::NftInfo {
+                token_id: token_id.to_string(),
+            });
+            let res: NftInfoResponse<TokenMetadata> =
+                from_binary(&query(deps, mock_env(), query_msg).unwrap()).unwrap();
+            res.extension
+        };
+
+        // only the owner picks the tier of sold seats, among existing tiers
+        let set_sale_tier = |tier_id: &str| ExecuteMsg::SetSaleTier {
+            tier_id: Some(tier_id.to_string()),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            set_sale_tier("silver"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            set_sale_tier("bronze"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TierNotFound { .. }));
+        execute(deps.as_mut(), env.clone(), info, set_sale_tier("silver")).unwrap();
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::SaleTier {}).unwrap();
+        assert_eq!(
+            from_binary::<Option<String>>(&res).unwrap(),
+            Some("silver".to_string())
+        );
+
+        // buyers cannot pick another tier, sold seats get the sale tier
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            buyer.clone(),
+            buy("1", json!({ "tier": "gold" })),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidTier { .. }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            buyer.clone(),
+            buy("1", json!({})),
+        )
+        .unwrap();
+        assert_eq!(seat(deps.as_ref(), "1").tier, Some("silver".to_string()));
+
+        // sold seats are perpetual unless renewals are configured, in which
+        // case they last one renewal duration, buyers cannot pick the expiry
+        assert_eq!(seat(deps.as_ref(), "1").expires_at, None);
+        let set_renewal_config = ExecuteMsg::SetRenewalConfig {
+            config: Some(RenewalConfigMsg {
+                price: Coin::new(5, "ustake"),
+                duration: 1000,
+                recipient: CREATOR.to_string(),
+            }),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            set_renewal_config,
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            buyer.clone(),
+            buy("2", json!({ "expires_at": "4102444800000000000" })),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidRenewal { .. }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            buyer.clone(),
+            buy("2", json!({})),
+        )
+        .unwrap();
+        assert_eq!(
+            seat(deps.as_ref(), "2").expires_at,
+            Some(env.block.time.plus_seconds(1000))
+        );
+
+        // sold seats carry the royalty set by the owner, not the buyer's
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            buyer.clone(),
+            buy("3", json!({ "royalty_percentage": 0 })),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidRoyalty { .. }));
+        let set_royalty_config = |percentage: u64| ExecuteMsg::SetRoyaltyConfig {
+            config: Some(RoyaltyConfigMsg {
+                percentage,
+                payment_address: "royalties".to_string(),
+            }),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            set_royalty_config(5),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            set_royalty_config(26),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidRoyalty { .. }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            set_royalty_config(5),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            buyer.clone(),
+            buy("3", json!({})),
+        )
+        .unwrap();
+        let extension = seat(deps.as_ref(), "3");
+        assert_eq!(extension.royalty_percentage, Some(5));
+        assert_eq!(
+            extension.royalty_payment_address,
+            Some("royalties".to_string())
+        );
+
+        // the sale is no longer charged once its denom is not accepted
+        let set_payment_denoms = ExecuteMsg::SetPaymentDenoms {
+            denoms: vec!["ustake".to_string()],
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            set_payment_denoms,
+        )
+        .unwrap();
+        let err = execute(deps.as_mut(), env, buyer, buy("4", json!({}))).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::UnsupportedPaymentAsset { denom } if denom == "USDC"
+        ));
+    }
+
+    #[test]
+    fn test_auctions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+        for token_id in ["1", "2"] {
+            let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+                token_id: token_id.to_string(),
+                owner: CREATOR.to_string(),
+                token_uri: None,
+                extension: TokenMetadata {
+                    name: None,
+                    description: None,
+                    royalty_percentage: None,
+                    royalty_payment_address: None,
+                    tier: None,
+                    expires_at: None,
+                },
+            }));
+            execute(deps.as_mut(), env.clone(), info.clone(), mint_msg).unwrap();
+        }
+        let list_msg = SellableExecuteMsg::List {
+            listings: Map::from([("1".to_string(), Coin::new(2000, "uturnt"))]),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&json!({ "sellable": list_msg }).to_string()).unwrap(),
+        )
+        .unwrap();
+        let end = env.block.time.plus_seconds(1000);
+        let auction_info = |deps: Deps, env: Env, token_id: &str| -> AuctionInfo {
+            let res = query(
+                deps,
+                env,
+                QueryMsg::Auction {
+                    token_id: token_id.to_string(),
+                },
+            )
+            .unwrap();
+            from_binary(&res).unwrap()
+        };
+        let owner_of = |token_id: &str| Cw721BaseQueryMsg::<Cw721QueryMsg>::OwnerOf {
+            token_id: token_id.to_string(),
+            include_expired: None,
+        };
+        let later = |seconds: u64| {
+            let mut env = mock_env();
+            env.block.time = env.block.time.plus_seconds(seconds);
+            env
+        };
+
+        // only the owner can auction a seat, in a payment denom
+        let dutch = || AuctionKind::Dutch {
+            start_price: Uint128::new(1000),
+            floor_price: Uint128::new(100),
+        };
+        let start_auction = |denom: &str, kind: AuctionKind| ExecuteMsg::StartAuction {
+            token_id: "1".to_string(),
+            denom: denom.to_string(),
+            kind,
+            start: None,
+            end,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            start_auction("uturnt", dutch()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotSeatOwner { .. }));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            start_auction("uatom", dutch()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidAuction { .. }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            start_auction("uturnt", dutch()),
+        )
+        .unwrap();
+
+        // the auction replaces the listing and blocks transfers
+        let query_msg = SellableQueryMsg::ListedTokens {
+            start_after: None,
+            limit: None,
+        };
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            from_str(&json!({ "sellable": query_msg }).to_string()).unwrap(),
+        )
+        .unwrap();
+        let result: SellableQueryResp<TokenMetadata> = from_binary(&res).unwrap();
+        match result {
+            SellableQueryResp::ListedTokens(res) => assert!(res.is_empty()),
+        }
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::TransferNft {
+                recipient: USER.to_string(),
+                token_id: "1".to_string(),
+            }),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::SeatInAuction { .. }));
+
+        // the Dutch price decays linearly towards the floor
+        let auction = auction_info(deps.as_ref(), later(500), "1");
+        assert_eq!(auction.asking_price, Some(Coin::new(550, "uturnt")));
+        let res = query(
+            deps.as_ref(),
+            later(500),
+            QueryMsg::AllSeats {
+                start_after: None,
+                limit: None,
+                owner: None,
+                listed_only: Some(true),
+                min_price: None,
+                max_price: None,
+            },
+        )
+        .unwrap();
+        let seats: AllSeatsResponse = from_binary(&res).unwrap();
+        assert_eq!(seats.seats.len(), 1);
+        assert_eq!(seats.seats[0].listed_price, Some(Coin::new(550, "uturnt")));
+        assert!(seats.seats[0].auction.is_some());
+        let err = execute(
+            deps.as_mut(),
+            later(500),
+            mock_info(USER, &[Coin::new(500, "uturnt")]),
+            ExecuteMsg::BuyAuction {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InsufficientFunds { .. }));
+        let res = execute(
+            deps.as_mut(),
+            later(500),
+            mock_info(USER, &[Coin::new(600, "uturnt")]),
+            ExecuteMsg::BuyAuction {
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: CREATOR.to_string(),
+                    amount: vec![Coin::new(550, "uturnt")],
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: USER.to_string(),
+                    amount: vec![Coin::new(50, "uturnt")],
+                }),
+            ]
+        );
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            from_str(&json!({ "seat_token": owner_of("1") }).to_string()).unwrap(),
+        )
+        .unwrap();
+        let owner: OwnerOfResponse = from_binary(&res).unwrap();
+        assert_eq!(owner.owner, USER);
+
+        // English auctions escrow the highest bid and refund the outbid one
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::StartAuction {
+                token_id: "2".to_string(),
+                denom: "uturnt".to_string(),
+                kind: AuctionKind::English {
+                    reserve_price: Uint128::new(100),
+                    min_increment: Uint128::new(10),
+                },
+                start: None,
+                end,
+            },
+        )
+        .unwrap();
+        let bid_auction = ExecuteMsg::BidAuction {
+            token_id: "2".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[Coin::new(50, "uturnt")]),
+            bid_auction.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::BidTooLow { .. }));
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[Coin::new(100, "uturnt")]),
+            bid_auction.clone(),
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bob", &[Coin::new(105, "uturnt")]),
+            bid_auction.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::BidTooLow { min_bid } if min_bid == Coin::new(110, "uturnt")
+        ));
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bob", &[Coin::new(150, "uturnt")]),
+            bid_auction,
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: "alice".to_string(),
+                amount: vec![Coin::new(100, "uturnt")],
+            })]
+        );
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::CancelAuction {
+                token_id: "2".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidAuction { .. }));
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Auctions {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let auctions: AuctionsResponse = from_binary(&res).unwrap();
+        assert_eq!(auctions.auctions.len(), 1);
+        assert_eq!(
+            auctions.auctions[0].asking_price,
+            Some(Coin::new(160, "uturnt"))
+        );
+
+        // the highest bidder wins the seat once the auction ended
+        let settle = ExecuteMsg::SettleAuction {
+            token_id: "2".to_string(),
+        };
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), settle.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::AuctionNotEnded { .. }));
+        let err = execute(
+            deps.as_mut(),
+            later(1000),
+            mock_info("carol", &[Coin::new(500, "uturnt")]),
+            ExecuteMsg::BidAuction {
+                token_id: "2".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::AuctionNotOpen { .. }));
+        let res = execute(deps.as_mut(), later(1000), mock_info("carol", &[]), settle).unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: CREATOR.to_string(),
+                amount: vec![Coin::new(150, "uturnt")],
+            })]
+        );
+        let res = query(
+            deps.as_ref(),
+            env,
+            from_str(&json!({ "seat_token": owner_of("2") }).to_string()).unwrap(),
+        )
+        .unwrap();
+        let owner: OwnerOfResponse = from_binary(&res).unwrap();
+        assert_eq!(owner.owner, "bob");
+    }
+
+    #[test]
+    fn test_offers() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+        for token_id in ["1", "2"] {
+            let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+                token_id: token_id.to_string(),
+                owner: CREATOR.to_string(),
+                token_uri: None,
+                extension: TokenMetadata {
+                    name: None,
+                    description: None,
+                    royalty_percentage: None,
+                    royalty_payment_address: None,
+                    tier: None,
+                    expires_at: None,
+                },
+            }));
+            execute(deps.as_mut(), env.clone(), info.clone(), mint_msg).unwrap();
+        }
+        let expires_at = env.block.time.plus_seconds(100);
+        let make_offer = |token_id: Option<&str>| ExecuteMsg::MakeOffer {
+            token_id: token_id.map(str::to_string),
+            expires_at,
+        };
+        let offers = |deps: Deps, token_id: Option<&str>, buyer: Option<&str>| -> Vec<u64> {
+            let res = query(
+                deps,
+                mock_env(),
+                QueryMsg::Offers {
+                    token_id: token_id.map(str::to_string),
+                    buyer: buyer.map(str::to_string),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+            let res: OffersResponse = from_binary(&res).unwrap();
+            res.offers.into_iter().map(|offer| offer.id).collect()
+        };
+        let refund = |to: &str, amount: u128| {
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: to.to_string(),
+                amount: vec![Coin::new(amount, "uturnt")],
+            })]
+        };
+
+        // offers escrow a single payment coin, owners cannot bid on their seat
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[Coin::new(200, "uturnt")]),
+            make_offer(Some("1")),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidOffer { .. }));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[Coin::new(200, "uatom")]),
+            make_offer(Some("1")),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidOffer { .. }));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[Coin::new(200, "uturnt")]),
+            ExecuteMsg::MakeOffer {
+                token_id: Some("1".to_string()),
+                expires_at: env.block.time,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidOffer { .. }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[Coin::new(200, "uturnt")]),
+            make_offer(Some("1")),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[Coin::new(300, "uturnt")]),
+            make_offer(None),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bob", &[Coin::new(150, "uturnt")]),
+            make_offer(Some("2")),
+        )
+        .unwrap();
+        assert_eq!(offers(deps.as_ref(), None, None), vec![1, 2, 3]);
+        assert_eq!(offers(deps.as_ref(), Some("1"), None), vec![1, 2]);
+        assert_eq!(offers(deps.as_ref(), None, Some("bob")), vec![3]);
+
+        // the seat owner accepts an offer for the seat
+        let accept = |offer_id: u64, token_id: &str| ExecuteMsg::AcceptOffer {
+            offer_id,
+            token_id: token_id.to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            accept(1, "1"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotSeatOwner { .. }));
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), accept(1, "2")).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidOffer { .. }));
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), accept(1, "1")).unwrap();
+        assert_eq!(res.messages, refund(CREATOR, 200));
+        let query_msg = Cw721BaseQueryMsg::<Cw721QueryMsg>::OwnerOf {
+            token_id: "1".to_string(),
+            include_expired: None,
+        };
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            from_str(&json!({ "seat_token": query_msg }).to_string()).unwrap(),
+        )
+        .unwrap();
+        let owner: OwnerOfResponse = from_binary(&res).unwrap();
+        assert_eq!(owner.owner, USER);
+        let err = execute(deps.as_mut(), env.clone(), info.clone(), accept(1, "1")).unwrap_err();
+        assert!(matches!(err, ContractError::OfferNotFound { offer_id: 1 }));
+
+        // owners reject offers for their seats, not collection offers
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::RejectOffer { offer_id: 2 },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidOffer { .. }));
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::RejectOffer { offer_id: 3 },
+        )
+        .unwrap();
+        assert_eq!(res.messages, refund("bob", 150));
+
+        // only the buyer cancels an offer until it expires
+        let cancel = ExecuteMsg::CancelOffer { offer_id: 2 };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bob", &[]),
+            cancel.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let mut expired = mock_env();
+        expired.block.time = expires_at;
+        let err = execute(deps.as_mut(), expired.clone(), info, accept(2, "2")).unwrap_err();
+        assert!(matches!(err, ContractError::OfferExpired { offer_id: 2 }));
+        let res = execute(deps.as_mut(), expired, mock_info("bob", &[]), cancel).unwrap();
+        assert_eq!(res.messages, refund("alice", 300));
+        assert!(offers(deps.as_ref(), None, None).is_empty());
+
+        // a page stops after going through 100 offers, even without a match
+        for buyer in ["carol"; 100].into_iter().chain(["dave"]) {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(buyer, &[Coin::new(10, "uturnt")]),
+                make_offer(None),
+            )
+            .unwrap();
+        }
+        let page = |start_after: Option<u64>| -> OffersResponse {
+            let query_msg = QueryMsg::Offers {
+                token_id: None,
+                buyer: Some("dave".to_string()),
+                start_after,
+                limit: None,
+            };
+            from_binary(&query(deps.as_ref(), env.clone(), query_msg).unwrap()).unwrap()
+        };
+        let res = page(None);
+        assert!(res.offers.is_empty());
+        assert_eq!(res.next_start_after, Some(103));
+        let res = page(Some(103));
+        assert_eq!(res.offers.len(), 1);
+        assert_eq!(res.offers[0].id, 104);
+        assert_eq!(res.next_start_after, None);
+    }
+
+    #[test]
+    fn test_listings() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+        for token_id in ["1", "2", "3"] {
+            let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+                token_id: token_id.to_string(),
+                owner: CREATOR.to_string(),
+                token_uri: None,
+                extension: TokenMetadata {
+                    name: None,
+                    description: None,
+                    royalty_percentage: None,
+                    royalty_payment_address: None,
+                    tier: None,
+                    expires_at: None,
+                },
+            }));
+            execute(deps.as_mut(), env.clone(), info.clone(), mint_msg).unwrap();
+        }
+        let expires_at = env.block.time.plus_seconds(100);
+        let mut expired = mock_env();
+        expired.block.time = expires_at;
+        let listed = |deps: Deps, env: Env| -> Vec<(String, Coin, Option<Timestamp>)> {
+            let res = query(
+                deps,
+                env,
+                QueryMsg::AllSeats {
+                    start_after: None,
+                    limit: None,
+                    owner: None,
+                    listed_only: Some(true),
+                    min_price: None,
+                    max_price: None,
+                },
+            )
+            .unwrap();
+            let res: AllSeatsResponse = from_binary(&res).unwrap();
+            res.seats
+                .into_iter()
+                .map(|seat| {
+                    (
+                        seat.token_id,
+                        seat.listed_price.unwrap(),
+                        seat.listing_expires_at,
+                    )
+                })
+                .collect()
+        };
+
+        // listings expire at their optional expiry
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::ListSeats {
+                listings: vec![ListingMsg {
+                    token_id: "1".to_string(),
+                    price: Coin::new(100, "uturnt"),
+                    expires_at: Some(env.block.time),
+                }],
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidListing { .. }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::ListSeats {
+                listings: vec![
+                    ListingMsg {
+                        token_id: "1".to_string(),
+                        price: Coin::new(100, "uturnt"),
+                        expires_at: Some(expires_at),
+                    },
+                    ListingMsg {
+                        token_id: "2".to_string(),
+                        price: Coin::new(200, "uturnt"),
+                        expires_at: None,
+                    },
+                ],
+            },
+        )
+        .unwrap();
+
+        // updating prices keeps the expiry of the listings
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::UpdatePrices {
+                prices: Map::from([("3".to_string(), Coin::new(300, "uturnt"))]),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TokenNotListed { .. }));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::UpdatePrices {
+                prices: Map::from([("1".to_string(), Coin::new(150, "uturnt"))]),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            listed(deps.as_ref(), env.clone()),
+            vec![
+                ("1".to_string(), Coin::new(150, "uturnt"), Some(expires_at)),
+                ("2".to_string(), Coin::new(200, "uturnt"), None),
+            ]
+        );
+
+        // expired listings can no longer be bought
+        assert_eq!(
+            listed(deps.as_ref(), expired.clone()),
+            vec![("2".to_string(), Coin::new(200, "uturnt"), None)]
+        );
+        let err = execute(
+            deps.as_mut(),
+            expired,
+            mock_info(USER, &[Coin::new(150, "uturnt")]),
+            from_str(&json!({"sellable": {"buy_token": {"token_id": "1"}}}).to_string()).unwrap(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ListingExpired { .. }));
+
+        // transferring a seat delists it
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::TransferNft {
+                recipient: USER.to_string(),
+                token_id: "2".to_string(),
+            }),
+        )
+        .unwrap();
+        assert_eq!(
+            listed(deps.as_ref(), env.clone()),
+            vec![("1".to_string(), Coin::new(150, "uturnt"), Some(expires_at))]
+        );
+
+        // relisting without expiry clears it, delisting all removes every listing
+        let list_msg = SellableExecuteMsg::List {
+            listings: Map::from([
+                ("1".to_string(), Coin::new(100, "uturnt")),
+                ("3".to_string(), Coin::new(300, "uturnt")),
+            ]),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&json!({ "sellable": list_msg }).to_string()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            listed(deps.as_ref(), env.clone()),
+            vec![
+                ("1".to_string(), Coin::new(100, "uturnt"), None),
+                ("3".to_string(), Coin::new(300, "uturnt"), None),
+            ]
+        );
+        let res = execute(deps.as_mut(), env.clone(), info, ExecuteMsg::DelistAll {}).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "delisted" && attr.value == "2"));
+        assert!(listed(deps.as_ref(), env).is_empty());
+    }
+
+    #[test]
+    fn test_cw20_payments() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt", "cw20:token"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::PaymentAssets {}).unwrap();
+        let assets: Vec<PaymentAsset> = from_binary(&res).unwrap();
+        assert_eq!(
+            assets,
+            vec![
+                PaymentAsset::Native {
+                    denom: "uturnt".to_string()
+                },
+                PaymentAsset::Cw20 {
+                    address: Addr::unchecked("token")
+                },
+            ]
+        );
+        // the seat token module charges in the first denom, which must be native
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::SetPaymentDenoms {
+                denoms: vec!["cw20:token".to_string(), "uturnt".to_string()],
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidPaymentDenoms { .. }));
+
+        // seats are listed in any payment asset
+        let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+            token_id: "1".to_string(),
+            owner: CREATOR.to_string(),
+            token_uri: None,
+            extension: TokenMetadata {
+                name: None,
+                description: None,
+                royalty_percentage: None,
+                royalty_payment_address: None,
+                tier: None,
+                expires_at: None,
+            },
+        }));
+        execute(deps.as_mut(), env.clone(), info.clone(), mint_msg).unwrap();
+        let list = |denom: &str| ExecuteMsg::ListSeats {
+            listings: vec![ListingMsg {
+                token_id: "1".to_string(),
+                price: Coin::new(500, denom),
+                expires_at: None,
+            }],
+        };
+        let err =
+            execute(deps.as_mut(), env.clone(), info.clone(), list("cw20:other")).unwrap_err();
+        assert!(matches!(err, ContractError::UnsupportedPaymentAsset { .. }));
+        execute(deps.as_mut(), env.clone(), info, list("cw20:token")).unwrap();
+
+        // native coins named after a CW20 token are not a payment
+        let buy_token = ExecuteMsg::Sellable(sellable::msg::ExecuteMsg::BuyToken {
+            token_id: "1".to_string(),
+        });
+        let make_offer = ExecuteMsg::MakeOffer {
+            token_id: Some("1".to_string()),
+            expires_at: env.block.time.plus_seconds(60),
+        };
+        for msg in [buy_token, make_offer] {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[Coin::new(500, "cw20:token")]),
+                msg,
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err,
+                ContractError::UnsupportedPaymentAsset { denom } if denom == "cw20:token"
+            ));
+        }
+
+        // CW20 tokens sent with a paid message pay for it
+        let receive = |amount: u128, msg: serde_json::Value| {
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: Uint128::new(amount),
+                msg: to_binary(&msg).unwrap(),
+            })
+        };
+        let buy_token = json!({"sellable": {"buy_token": {"token_id": "1"}}});
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("other", &[]),
+            receive(500, buy_token.clone()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::UnsupportedPaymentAsset { .. }));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("token", &[]),
+            receive(500, json!({"delist_all": {}})),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::UnsupportedMessage {}));
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("token", &[]),
+            receive(400, buy_token.clone()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InsufficientFunds { .. }));
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("token", &[]),
+            receive(500, buy_token),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(WasmMsg::Execute {
+                contract_addr: "token".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: CREATOR.to_string(),
+                    amount: Uint128::new(500),
+                })
+                .unwrap(),
+                funds: vec![],
+            })]
+        );
+        let query_msg = Cw721BaseQueryMsg::<Cw721QueryMsg>::OwnerOf {
+            token_id: "1".to_string(),
+            include_expired: None,
+        };
+        let res = query(
+            deps.as_ref(),
+            env,
+            from_str(&json!({ "seat_token": query_msg }).to_string()).unwrap(),
+        )
+        .unwrap();
+        let owner: OwnerOfResponse = from_binary(&res).unwrap();
+        assert_eq!(owner.owner, USER);
+    }
+
+    #[test]
+    fn test_payment_validation() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt", "uatom"]
+        })
+        .to_string();
+        let info = mock_info(CREATOR, &[]);
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+        let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+            token_id: "1".to_string(),
+            owner: CREATOR.to_string(),
+            token_uri: None,
+            extension: TokenMetadata {
+                name: None,
+                description: None,
+                royalty_percentage: None,
+                royalty_payment_address: None,
+                tier: None,
+                expires_at: None,
+            },
+        }));
+        execute(deps.as_mut(), env.clone(), info.clone(), mint_msg).unwrap();
+        let list_msg = SellableExecuteMsg::List {
+            listings: Map::from([("1".to_string(), Coin::new(1000, "uturnt"))]),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            from_str(&json!({ "sellable": list_msg }).to_string()).unwrap(),
+        )
+        .unwrap();
+        let buy = |funds: &[Coin]| {
+            let buy_msg = json!({"sellable": {"buy_token": {"token_id": "1"}}});
+            (
+                mock_info("buyer", funds),
+                from_str::<ExecuteMsg>(&buy_msg.to_string()).unwrap(),
+            )
+        };
+
+        // payments are a single coin of the price denom, worth at least the price
+        let (info, msg) = buy(&[]);
+        let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InsufficientFunds { expected, received }
+                if expected == Coin::new(1000, "uturnt") && received == Coin::new(0, "uturnt")
+        ));
+        let (info, msg) = buy(&[Coin::new(1000, "uatom")]);
+        let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::WrongDenom { expected, received }
+                if expected == "uturnt" && received == "uatom"
+        ));
+        let (info, msg) = buy(&[Coin::new(1000, "uturnt"), Coin::new(1, "uatom")]);
+        let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::WrongDenom { .. }));
+        let (info, msg) = buy(&[Coin::new(999, "uturnt")]);
+        let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::InsufficientFunds { .. }));
+
+        // the excess is refunded in the same transaction
+        let (info, msg) = buy(&[Coin::new(1200, "uturnt")]);
+        let res = execute(deps.as_mut(), env, info, msg).unwrap();
+        assert_eq!(
+            res.messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: CREATOR.to_string(),
+                    amount: vec![Coin::new(1000, "uturnt")],
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: "buyer".to_string(),
+                    amount: vec![Coin::new(200, "uturnt")],
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_error_messages() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let metadata_msg = SeatMetadata {
+            name: "Kenny's contract".to_string(),
+            image_uri: "image".to_owned(),
+            description: "description".to_string(),
+            benefits: vec![],
+            template_number: 1,
+            image_settings: ImageSettings {
+                seat_name: true,
+                hub_name: true,
+            },
+        };
+        let msg = json!({
+            "seat_token": {
+                "name": "Kenny's Token Contract".to_string(),
+                "symbol": "KNY".to_string(),
+                "minter": CREATOR.to_string(),
+            },
+            "metadata": {
+                "metadata": metadata_msg
+            },
+            "ownable": {
+                "owner": CREATOR
+            },
+            "redeemable": {
+                "locked_items": Set::<String>::new()
+            },
+            "sellable": {
+                "tokens": Map::<&str, Coin>::new()
+            },
+            "sales": {},
+            "hub_contract": "cosmos188rjfzzrdxlus60zgnrvs4rg0l73hct3azv93z",
+            "payment_denoms": ["uturnt"]
+        })
+        .to_string();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            from_str(&msg).unwrap(),
+        )
+        .unwrap();
+        let mint_msg = ExecuteMsg::SeatToken(Cw721BaseExecuteMsg::Mint(MintMsg {
+            token_id: "1".to_string(),
+            owner: CREATOR.to_string(),
+            token_uri: None,
+            extension: TokenMetadata {
+                name: None,
+                description: None,
+                royalty_percentage: None,
+                royalty_payment_address: None,
+                tier: None,
+                expires_at: None,
+            },
+        }));
+
+        // module errors carry their cause
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            mint_msg.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::SeatTokenError(_)));
+        let message = err.to_string();
+        assert!(message.starts_with("Seat token module error: "));
+        assert!(message.len() > "Seat token module error: ".len());
+
+        // seats are looked up and owned explicitly
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            mint_msg,
+        )
+        .unwrap();
+        let start_auction = |token_id: &str| ExecuteMsg::StartAuction {
+            token_id: token_id.to_string(),
+            denom: "uturnt".to_string(),
+            kind: AuctionKind::English {
+                reserve_price: Uint128::new(100),
+                min_increment: Uint128::new(10),
+            },
+            start: None,
+            end: env.block.time.plus_seconds(100),
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CREATOR, &[]),
+            start_auction("2"),
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "Seat 2 not found");
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            start_auction("1"),
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), format!("{} does not own seat 1", USER));
+
+        // the frontend matches on these
+        let messages = [
+            (ContractError::Unauthorized {}, "Unauthorized"),
+            (ContractError::SaleNotActive {}, "No sale is active"),
+            (
+                ContractError::TierSoldOut {
+                    tier_id: "gold".to_string(),
+                },
+                "Tier gold is sold out",
+            ),
+            (
+                ContractError::NonTransferable {
+                    token_id: "1".to_string(),
+                },
+                "Seat 1 is not transferable",
+            ),
+            (
+                ContractError::InsufficientFunds {
+                    expected: Coin::new(100, "uturnt"),
+                    received: Coin::new(50, "uturnt"),
+                },
+                "Insufficient funds, expected 100uturnt, received 50uturnt",
+            ),
+            (
+                ContractError::WrongDenom {
+                    expected: "uturnt".to_string(),
+                    received: "uatom".to_string(),
+                },
+                "Wrong denom, expected uturnt, received uatom",
+            ),
+        ];
+        for (err, message) in messages {
+            assert_eq!(err.to_string(), message);
+        }
+    }
 }
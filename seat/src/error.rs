@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Coin, StdError};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -9,21 +9,132 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
-    #[error("Ownable Module Error")]
+    #[error("Ownable module error: {0}")]
     OwnableError(#[from] ownable::OwnableError),
 
-    #[error("Metadata Module Error")]
+    #[error("Metadata module error: {0}")]
     MetadataError(#[from] metadata::MetadataError),
 
-    #[error("Token Module Error")]
+    #[error("Seat token module error: {0}")]
     SeatTokenError(#[from] cw721_base::ContractError),
 
-    #[error("Redeemable Module Error")]
+    #[error("Redeemable module error: {0}")]
     RedeemableError(#[from] redeemable::errors::ContractError),
 
-    #[error(transparent)]
+    #[error("Sellable module error: {0}")]
     SellableError(#[from] sellable::errors::ContractError),
 
-    #[error("Sales Module Error")]
+    #[error("Sales module error: {0}")]
     SalesError(#[from] sales::errors::ContractError),
+
+    #[error("Unsupported message type")]
+    UnsupportedMessage {},
+
+    #[error("{denom} is not a payment denom")]
+    UnsupportedPaymentAsset { denom: String },
+
+    #[error("Invalid payment denoms: {reason}")]
+    InvalidPaymentDenoms { reason: String },
+
+    #[error("Seat {token_id} not found")]
+    SeatNotFound { token_id: String },
+
+    #[error("{sender} does not own seat {token_id}")]
+    NotSeatOwner { token_id: String, sender: String },
+
+    #[error("Tier {tier_id} not found")]
+    TierNotFound { tier_id: String },
+
+    #[error("Tier {tier_id} is sold out")]
+    TierSoldOut { tier_id: String },
+
+    #[error("Invalid tier: {reason}")]
+    InvalidTier { reason: String },
+
+    #[error("Invalid royalty: {reason}")]
+    InvalidRoyalty { reason: String },
+
+    #[error("Invalid fee: {reason}")]
+    InvalidFee { reason: String },
+
+    #[error("The fee admin cannot be the contract owner")]
+    FeeAdminIsOwner {},
+
+    #[error("Seat {token_id} is not transferable")]
+    NonTransferable { token_id: String },
+
+    #[error("Seat {token_id} has expired")]
+    SeatExpired { token_id: String },
+
+    #[error("Seat {token_id} cannot be renewed")]
+    NotRenewable { token_id: String },
+
+    #[error("Invalid renewal: {reason}")]
+    InvalidRenewal { reason: String },
+
+    #[error("Invalid subscription: {reason}")]
+    InvalidSubscription { reason: String },
+
+    #[error("Invalid sale phase {phase_id}: {reason}")]
+    InvalidSalePhase { phase_id: String, reason: String },
+
+    #[error("No sale is active")]
+    SaleNotActive {},
+
+    #[error("{address} is not allowed in the active sale phase")]
+    NotAllowlisted { address: String },
+
+    #[error("Mint limit of sale phase {phase_id} reached")]
+    PhaseMintLimitReached { phase_id: String },
+
+    #[error("Invalid Merkle proof")]
+    InvalidMerkleProof {},
+
+    #[error("Invalid auction: {reason}")]
+    InvalidAuction { reason: String },
+
+    #[error("Seat {token_id} is being auctioned")]
+    SeatInAuction { token_id: String },
+
+    #[error("Seat {token_id} is not auctioned")]
+    AuctionNotFound { token_id: String },
+
+    #[error("The auction of seat {token_id} is not open")]
+    AuctionNotOpen { token_id: String },
+
+    #[error("The auction of seat {token_id} has not ended")]
+    AuctionNotEnded { token_id: String },
+
+    #[error("Bids must be at least {min_bid}")]
+    BidTooLow { min_bid: Coin },
+
+    #[error("Invalid offer: {reason}")]
+    InvalidOffer { reason: String },
+
+    #[error("Offer {offer_id} not found")]
+    OfferNotFound { offer_id: u64 },
+
+    #[error("Offer {offer_id} has expired")]
+    OfferExpired { offer_id: u64 },
+
+    #[error("Token {token_id} is not listed")]
+    TokenNotListed { token_id: String },
+
+    #[error("The listing of token {token_id} has expired")]
+    ListingExpired { token_id: String },
+
+    #[error("Invalid listing: {reason}")]
+    InvalidListing { reason: String },
+
+    #[error("Insufficient funds, expected {expected}, received {received}")]
+    InsufficientFunds { expected: Coin, received: Coin },
+
+    #[error("Wrong denom, expected {expected}, received {received}")]
+    WrongDenom { expected: String, received: String },
+
+    #[error("Sub-message {id} failed: {error}")]
+    SubMsgFailed { id: u64, error: String },
+
+    #[error("No contract or module replies to id {id}")]
+    UnknownReply { id: u64 },
 }
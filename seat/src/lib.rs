@@ -1,6 +1,16 @@
+pub mod assets;
+pub mod auctions;
 pub mod contract;
 mod error;
+pub mod listings;
+pub mod migrate;
 pub mod msg;
+pub mod offers;
+pub mod payments;
+pub mod phases;
+pub mod renewals;
 pub mod state;
+pub mod subscriptions;
+pub mod tiers;
 
 pub use crate::error::ContractError;
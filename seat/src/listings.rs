@@ -0,0 +1,12 @@
+use cosmwasm_std::{StdResult, Storage, Timestamp};
+use cw_storage_plus::Map;
+
+/// Expiry of the listings that have one, by token id. Expired listings cannot
+/// be bought and are not reported as listed.
+pub const LISTING_EXPIRIES: Map<&str, Timestamp> = Map::new("listing_expiries");
+
+pub fn listing_expired(storage: &dyn Storage, token_id: &str, now: &Timestamp) -> StdResult<bool> {
+    Ok(LISTING_EXPIRIES
+        .may_load(storage, token_id)?
+        .is_some_and(|expires_at| expires_at <= *now))
+}
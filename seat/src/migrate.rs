@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use cosmwasm_std::{
+    BondedDenomResponse, Coin, Deps, DepsMut, Order, QueryRequest, StakingQuery, StdError,
+    StdResult,
+};
+use cw721_base::state::TokenInfo;
+use cw_storage_plus::Map;
+use migrations::{parse_version, transform_item, transform_map, Migration, MigrationReport};
+use serde_json::Value;
+
+use crate::contract::CONTRACT_VERSION;
+use crate::payments::MAX_ROYALTY_PERCENTAGE;
+use crate::state::{Config, SeatMetadata, TokenMetadata, PAYMENT_DENOMS, TRANSFERABLE};
+
+/// Storage migrations of the seat contract, ordered by version
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: "0.1.0",
+        to: "0.2.0",
+        description: "Store the payment denoms",
+        migrate: store_payment_denoms,
+    },
+    Migration {
+        from: "0.2.0",
+        to: "0.3.0",
+        description: "Upgrade seats to tiers and expiries, drop stale listings and sales",
+        migrate: upgrade_seats,
+    },
+];
+
+/// Reports what migrating from `from_version`, by default the deployed
+/// version, to this version would change
+pub fn dry_run_migration(deps: Deps, from_version: Option<String>) -> StdResult<MigrationReport> {
+    let from_version = match from_version {
+        Some(version) => version,
+        None => cw2::get_contract_version(deps.storage)?.version,
+    };
+    migrations::dry_run(
+        deps,
+        MIGRATIONS,
+        &parse_version(&from_version)?,
+        &parse_version(CONTRACT_VERSION)?,
+    )
+}
+
+/// 0.2.0 stores the payment denoms, which used to be the chain's bonded
+/// denom queried on every call
+fn store_payment_denoms(deps: DepsMut) -> StdResult<()> {
+    if PAYMENT_DENOMS.may_load(deps.storage)?.is_some() {
+        return Ok(());
+    }
+    let bonded_denom: BondedDenomResponse = deps
+        .querier
+        .query(&QueryRequest::Staking(StakingQuery::BondedDenom {}))?;
+    PAYMENT_DENOMS.save(deps.storage, &vec![bonded_denom.denom])
+}
+
+/// 0.3.0 adds tiers, expiries, royalty and fee bounds and restricts sales to
+/// the payment denoms:
+/// - seats are rewritten with the new `TokenMetadata` layout, without a tier
+///   and perpetual, their royalty capped to `MAX_ROYALTY_PERCENTAGE`
+/// - listings of burned seats or priced in another denom are dropped
+/// - primary sales priced in another denom are disabled
+/// - seats stay transferable
+///
+/// The other items added by 0.3.0 (`fee_config`, `tiers`, `sale_tier`,
+/// `royalty_config`, `renewal_config`, `subscription_config`, `sale_phases`,
+/// `auctions`, `offers` and `listing_expiries`) start out unset, which
+/// leaves the matching feature off.
+fn upgrade_seats(deps: DepsMut) -> StdResult<()> {
+    let denoms = PAYMENT_DENOMS.load(deps.storage)?;
+
+    transform_map(
+        deps.storage,
+        "tokens",
+        |_, mut seat: TokenInfo<TokenMetadata>| {
+            seat.extension.royalty_percentage = seat
+                .extension
+                .royalty_percentage
+                .map(|percentage| percentage.min(MAX_ROYALTY_PERCENTAGE));
+            Ok(Some(seat))
+        },
+    )?;
+
+    let seats = Map::<&str, TokenInfo<TokenMetadata>>::new("tokens")
+        .keys_raw(deps.storage, None, None, Order::Ascending)
+        .collect::<HashSet<_>>();
+    transform_map(deps.storage, "listed_tokens", |token_id, price: Coin| {
+        let stale = !seats.contains(token_id) || !denoms.contains(&price.denom);
+        Ok((!stale).then_some(price))
+    })?;
+
+    // the layout belongs to the sales module, only the prices are read
+    transform_item(deps.storage, "primary_sales", |mut sales: Vec<Value>| {
+        for sale in sales.iter_mut() {
+            let prices: Vec<Coin> = serde_json::from_value(sale["price"].clone())
+                .map_err(|err| StdError::parse_err("primary sale price", err))?;
+            if prices.iter().any(|price| !denoms.contains(&price.denom)) {
+                sale["disabled"] = Value::Bool(true);
+            }
+        }
+        Ok(sales)
+    })?;
+
+    // unchanged, fails if they no longer decode
+    transform_item(deps.storage, "metadata", Ok::<SeatMetadata, _>)?;
+    transform_item(deps.storage, "config", Ok::<Config, _>)?;
+
+    if TRANSFERABLE.may_load(deps.storage)?.is_none() {
+        TRANSFERABLE.save(deps.storage, &true)?;
+    }
+    Ok(())
+}
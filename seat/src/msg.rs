@@ -1,9 +1,14 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin, Empty};
+use cosmwasm_std::{Addr, Coin, Empty, Timestamp, Uint128};
+use cw20::Cw20ReceiveMsg;
 use cw721_base::state::Approval;
+use cw721_base::MintMsg;
 use serde::{Deserialize, Serialize};
 
-use crate::state::{SeatMetadata, TokenMetadata};
+use crate::auctions::{Auction, AuctionKind};
+use crate::offers::Offer;
+use crate::state::{SeatBenefits, SeatMetadata, TokenMetadata};
+use crate::tiers::Tier;
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "snake_case")]
@@ -16,6 +21,21 @@ pub struct InstantiateMsg {
     pub sellable: Option<sellable::msg::InstantiateMsg>,
     pub sales: sales::msg::InstantiateMsg,
     pub hub_contract: String,
+    /// Denoms seats can be paid with, the first one is used by the token module
+    pub payment_denoms: Vec<String>,
+    /// Platform fee taken on seat sales
+    pub fee: Option<FeeConfigMsg>,
+    /// Whether seats can be transferred, sent or listed. Defaults to true.
+    pub transferable: Option<bool>,
+}
+
+#[cw_serde]
+pub struct FeeConfigMsg {
+    /// The only address allowed to update the fee
+    pub admin: String,
+    /// Fee in basis points of the sale price
+    pub fee_bps: u16,
+    pub collector: String,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -24,13 +44,214 @@ pub enum ExecuteMsg {
     Ownable(ownable::ExecuteMsg),
     Metadata(metadata::ExecuteMsg<SeatMetadata>),
     SeatToken(cw721_base::ExecuteMsg<TokenMetadata, Empty>),
+    Redeemable(redeemable::ExecuteMsg),
     Sellable(sellable::msg::ExecuteMsg),
     Sales(sales::msg::ExecuteMsg<TokenMetadata>),
+    /// Pays one of the messages taking funds, e.g. a `BuyItem` or a
+    /// `BuyToken`, with the CW20 tokens received
+    Receive(Cw20ReceiveMsg),
+    /// Replaces the accepted payment denoms, `cw20:<address>` for a CW20
+    /// token. Owner only.
+    SetPaymentDenoms {
+        denoms: Vec<String>,
+    },
+    /// Creates or updates a tier. Owner only.
+    SetTier {
+        tier_id: String,
+        tier: TierInfo,
+    },
+    /// Removes a tier none of whose seats were minted. Owner only.
+    RemoveTier {
+        tier_id: String,
+    },
+    /// Sets the tier of the seats sold in the primary sale, `None` sells
+    /// untiered seats. Owner only.
+    SetSaleTier {
+        tier_id: Option<String>,
+    },
+    /// Updates the platform fee, unset fields are kept. Fee admin only.
+    UpdateFeeConfig {
+        admin: Option<String>,
+        fee_bps: Option<u16>,
+        collector: Option<String>,
+    },
+    /// Makes the collection's seats transferable or soulbound. Owner only.
+    SetTransferable {
+        transferable: bool,
+    },
+    /// Burns a seat whatever its owner. Hub owner only.
+    RevokeSeat {
+        token_id: String,
+    },
+    /// Sets the royalty of the seats sold in the primary sale, `None` sells
+    /// them without royalty. Owner only.
+    SetRoyaltyConfig {
+        config: Option<RoyaltyConfigMsg>,
+    },
+    /// Sets how seats are renewed, `None` disables renewals. Owner only.
+    SetRenewalConfig {
+        config: Option<RenewalConfigMsg>,
+    },
+    /// Extends the expiry of a seat, paying the renewal price
+    RenewSeat {
+        token_id: String,
+    },
+    /// Sets how seats are subscribed to, `None` disables subscriptions. Owner
+    /// only.
+    SetSubscriptionConfig {
+        config: Option<SubscriptionConfigMsg>,
+    },
+    /// Pays `periods` billing periods of the subscription `token_id`, minting
+    /// the seat to the sender on the first payment
+    Subscribe {
+        token_id: String,
+        periods: u32,
+    },
+    /// Replaces the phases of the primary sale, an empty list opens it to
+    /// anyone. Owner only.
+    SetSalePhases {
+        phases: Vec<SalePhaseMsg>,
+    },
+    /// Adds and removes addresses of the allowlist of a phase. Owner only.
+    UpdateAllowlist {
+        phase_id: String,
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    /// Primary sale `BuyItem` proving the sender is in the Merkle allowlist
+    /// of the active phase
+    BuyAllowlisted {
+        proof: Vec<String>,
+        mint_msg: MintMsg<TokenMetadata>,
+    },
+    /// Auctions a seat the sender owns from `start`, by default now, to `end`
+    StartAuction {
+        token_id: String,
+        denom: String,
+        kind: AuctionKind,
+        start: Option<Timestamp>,
+        end: Timestamp,
+    },
+    /// Bids on an English auction, the outbid bidder is refunded
+    BidAuction {
+        token_id: String,
+    },
+    /// Buys the seat of a Dutch auction at its current price, any excess is
+    /// refunded
+    BuyAuction {
+        token_id: String,
+    },
+    /// Ends an auction once its end passed, the highest bidder of an English
+    /// auction wins the seat
+    SettleAuction {
+        token_id: String,
+    },
+    /// Cancels an auction without bids. Seller only.
+    CancelAuction {
+        token_id: String,
+    },
+    /// Lists seats the sender owns, each until its optional `expires_at`
+    ListSeats {
+        listings: Vec<ListingMsg>,
+    },
+    /// Changes the price of listed seats, keeping their expiry
+    UpdatePrices {
+        prices: schemars::Map<String, Coin>,
+    },
+    /// Delists all the seats of the sender
+    DelistAll {},
+    /// Escrows the funds sent as an offer for the seat `token_id`, or for any
+    /// seat of the collection if `None`, until `expires_at`
+    MakeOffer {
+        token_id: Option<String>,
+        expires_at: Timestamp,
+    },
+    /// Sells the seat `token_id` for an offer. Seat owner only.
+    AcceptOffer {
+        offer_id: u64,
+        token_id: String,
+    },
+    /// Refunds an offer for a seat. Seat owner only.
+    RejectOffer {
+        offer_id: u64,
+    },
+    /// Refunds an offer. Buyer only, or anyone once the offer expired.
+    CancelOffer {
+        offer_id: u64,
+    },
+}
+
+#[cw_serde]
+pub struct ListingMsg {
+    pub token_id: String,
+    pub price: Coin,
+    pub expires_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct RoyaltyConfigMsg {
+    /// Percent of every secondary sale price
+    pub percentage: u64,
+    pub payment_address: String,
+}
+
+#[cw_serde]
+pub struct RenewalConfigMsg {
+    /// Must be one of the payment denoms
+    pub price: Coin,
+    /// Seconds a renewal adds to the expiry of a seat
+    pub duration: u64,
+    pub recipient: String,
+}
+
+#[cw_serde]
+pub struct SalePhaseMsg {
+    pub id: String,
+    pub start: Timestamp,
+    /// The phase lasts until the next one starts if `None`
+    pub end: Option<Timestamp>,
+    /// Anyone can buy during the phase if `None`
+    pub allowlist: Option<AllowlistMsg>,
+    /// Seats an address can buy during the phase, unlimited if `None`
+    pub mint_limit: Option<u32>,
+}
+
+#[cw_serde]
+pub enum AllowlistMsg {
+    Addresses(Vec<String>),
+    /// Hex encoded root of a Merkle tree whose leaves are the sha256 hashes
+    /// of the allowed addresses
+    MerkleRoot(String),
+}
+
+#[cw_serde]
+pub struct SubscriptionConfigMsg {
+    /// Price of a single billing period, must be one of the payment denoms
+    pub price: Coin,
+    /// Seconds in a billing period
+    pub period: u64,
+    /// Seconds a seat stays active once its paid period ended
+    pub grace_period: u64,
+    pub recipient: String,
+    /// Tier of the seats minted for new subscriptions
+    pub tier: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct TierInfo {
+    pub name: String,
+    pub image_uri: String,
+    pub benefits: Vec<SeatBenefits>,
+    /// Seats of this tier that can ever be minted, unlimited if `None`
+    pub max_supply: Option<u64>,
+    /// Overrides the collection `transferable` setting when set
+    pub transferable: Option<bool>,
 }
 
 #[cw_serde]
 pub struct MigrateMsg {
-    pub owner: String,
+    /// Transfers the contract to this address when set
+    pub owner: Option<String>,
 }
 
 #[cw_serde]
@@ -41,17 +262,200 @@ pub enum QueryMsg {
     Redeemable(redeemable::QueryMsg),
     Sellable(sellable::msg::QueryMsg),
     Sales(sales::msg::QueryMsg),
-    AllSeats {},
+    /// Seats ordered by token id. Price filters only match listed seats.
+    AllSeats {
+        start_after: Option<String>,
+        limit: Option<u32>,
+        owner: Option<String>,
+        listed_only: Option<bool>,
+        min_price: Option<Uint128>,
+        max_price: Option<Uint128>,
+    },
+    /// The hub contract this seat collection was instantiated for
+    HubContract {},
+    /// The denoms seats can be paid with
+    PaymentDenoms {},
+    /// The native coins and CW20 tokens seats can be paid with
+    PaymentAssets {},
+    /// Reports what migrating from `from_version`, by default the deployed
+    /// version, to this version would change, without changing anything
+    DryRunMigration {
+        from_version: Option<String>,
+    },
+    /// A tier along with the number of its seats minted
+    Tier {
+        tier_id: String,
+    },
+    /// Tiers ordered by tier id
+    Tiers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// The tier of the seats sold in the primary sale, if any
+    SaleTier {},
+    /// Phases of the primary sale ordered by start
+    SalePhases {},
+    /// The phase of the primary sale currently running, if any
+    ActiveSalePhase {},
+    /// Whether `address` can buy seats in the active phase, `proof` is
+    /// required by Merkle allowlists
+    Eligibility {
+        address: String,
+        proof: Option<Vec<String>>,
+    },
+    /// An auction along with its current asking price
+    Auction {
+        token_id: String,
+    },
+    /// Auctions ordered by token id
+    Auctions {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    Offer {
+        offer_id: u64,
+    },
+    /// Offers ordered by id, optionally only those of `buyer` or those that
+    /// can buy the seat `token_id`, collection-wide ones included
+    Offers {
+        token_id: Option<String>,
+        buyer: Option<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Royalty owed when `token_id` is sold for `sale_price`, CW-2981 style
+    RoyaltyInfo {
+        token_id: String,
+        sale_price: Uint128,
+    },
+    /// The platform fee, if any
+    FeeConfig {},
+    /// The royalty of the seats sold in the primary sale, if any
+    RoyaltyConfig {},
+    /// Whether the seat can be transferred, sent or listed
+    IsTransferable {
+        token_id: String,
+    },
+    /// Whether the seat has not expired
+    IsActive {
+        token_id: String,
+    },
+    /// How seats are renewed, if they can be
+    RenewalConfig {},
+    /// How seats are subscribed to, if they can be
+    SubscriptionConfig {},
+    Subscription {
+        token_id: String,
+    },
+    /// Subscriptions whose paid period ends between `from` and `to` included,
+    /// ordered by end of paid period
+    ExpiringSubscriptions {
+        from: Timestamp,
+        to: Timestamp,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
 pub struct SeatInfo {
     pub token_id: String,
     pub listed_price: Option<Coin>,
+    /// Expiry of the listing, if any
+    pub listing_expires_at: Option<Timestamp>,
     pub owner: Addr,
     pub approvals: Vec<Approval>,
     pub token_uri: Option<String>,
     pub extension: TokenMetadata,
+    /// False once the seat has expired
+    pub active: bool,
+    /// The auction of the seat, `listed_price` is its asking price while open
+    pub auction: Option<Auction>,
+}
+
+#[cw_serde]
+pub struct AllSeatsResponse {
+    pub seats: Vec<SeatInfo>,
+    /// Token id to continue from, `None` once all matching seats were returned
+    pub next_start_after: Option<String>,
+}
+
+#[cw_serde]
+pub struct RoyaltiesInfoResponse {
+    /// Empty if the seat carries no royalty
+    pub address: String,
+    pub royalty_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct AuctionInfo {
+    pub token_id: String,
+    pub auction: Auction,
+    /// Current price of a Dutch auction, lowest acceptable bid of an English
+    /// one, `None` unless the auction is open
+    pub asking_price: Option<Coin>,
+}
+
+#[cw_serde]
+pub struct AuctionsResponse {
+    pub auctions: Vec<AuctionInfo>,
+}
+
+#[cw_serde]
+pub struct OffersResponse {
+    pub offers: Vec<Offer>,
+    /// Offer id to continue from, `None` once all matching offers were returned
+    pub next_start_after: Option<u64>,
+}
+
+#[cw_serde]
+pub struct EligibilityResponse {
+    /// The active phase, `None` if the sale has no phases or none is active
+    pub phase_id: Option<String>,
+    pub eligible: bool,
+    /// Seats the address bought during the active phase
+    pub minted: u32,
+    pub mint_limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct SubscriptionInfo {
+    pub token_id: String,
+    pub owner: Addr,
+    /// End of the paid period
+    pub paid_until: Timestamp,
+    /// End of the grace period, the seat is inactive from then on
+    pub expires_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct SubscriptionsResponse {
+    pub subscriptions: Vec<SubscriptionInfo>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct TierEntry {
+    pub tier_id: String,
+    pub tier: Tier,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct TiersResponse {
+    pub tiers: Vec<TierEntry>,
+}
+
+/// Queries the seat contract sends to its hub
+#[cw_serde]
+pub enum HubQueryMsg {
+    Ownable(ownable::QueryMsg),
+}
+
+/// Filters applied by the `AllSeats` query
+pub struct SeatFilters {
+    pub owner: Option<String>,
+    pub listed_only: Option<bool>,
+    pub min_price: Option<Uint128>,
+    pub max_price: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -0,0 +1,122 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Item, Map};
+
+use crate::msg::OffersResponse;
+use crate::state::PAYMENT_DENOMS;
+use crate::ContractError;
+
+/// Most offers `Offers` goes through per page, however few match its filters
+const MAX_SCANNED_OFFERS: usize = 100;
+
+#[cw_serde]
+pub struct Offer {
+    pub id: u64,
+    pub buyer: Addr,
+    /// Held in escrow until the offer is accepted, rejected or cancelled
+    pub price: Coin,
+    /// The seat the offer is for, `None` for a collection-wide offer any
+    /// seat owner can accept
+    pub token_id: Option<String>,
+    pub expires_at: Timestamp,
+}
+
+pub const OFFERS: Map<u64, Offer> = Map::new("offers");
+pub const OFFER_COUNT: Item<u64> = Item::new("offer_count");
+
+impl Offer {
+    pub fn is_expired(&self, now: &Timestamp) -> bool {
+        self.expires_at <= *now
+    }
+
+    /// Whether the offer can buy the seat `token_id`
+    pub fn is_for(&self, token_id: &str) -> bool {
+        self.token_id.as_deref().map_or(true, |id| id == token_id)
+    }
+}
+
+/// Escrows `funds` as a new offer of `buyer` and returns it
+pub fn make_offer(
+    storage: &mut dyn Storage,
+    now: &Timestamp,
+    buyer: Addr,
+    funds: &[Coin],
+    token_id: Option<String>,
+    expires_at: Timestamp,
+) -> Result<Offer, ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidOffer {
+        reason: reason.to_string(),
+    };
+    let price = match funds {
+        [coin] if !coin.amount.is_zero() => coin.clone(),
+        _ => return Err(invalid("offers must escrow a single coin")),
+    };
+    if !PAYMENT_DENOMS.load(storage)?.contains(&price.denom) {
+        return Err(invalid("the denom is not a payment denom"));
+    }
+    if expires_at <= *now {
+        return Err(invalid("offers must expire in the future"));
+    }
+
+    let id = OFFER_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    OFFER_COUNT.save(storage, &id)?;
+    let offer = Offer {
+        id,
+        buyer,
+        price,
+        token_id,
+        expires_at,
+    };
+    OFFERS.save(storage, id, &offer)?;
+    Ok(offer)
+}
+
+pub fn load_offer(storage: &dyn Storage, offer_id: u64) -> Result<Offer, ContractError> {
+    OFFERS
+        .may_load(storage, offer_id)?
+        .ok_or(ContractError::OfferNotFound { offer_id })
+}
+
+/// Offers ordered by id, optionally only those of `buyer` or those that can
+/// buy the seat `token_id`
+pub fn list_offers(
+    storage: &dyn Storage,
+    token_id: Option<&str>,
+    buyer: Option<&Addr>,
+    start_after: Option<u64>,
+    limit: usize,
+) -> StdResult<OffersResponse> {
+    let entries = OFFERS.range(
+        storage,
+        start_after.map(Bound::exclusive),
+        None,
+        Order::Ascending,
+    );
+
+    let mut offers = vec![];
+    let mut last_scanned = None;
+    let mut exhausted = true;
+    for (scanned, entry) in entries.enumerate() {
+        if offers.len() == limit || scanned == MAX_SCANNED_OFFERS {
+            exhausted = false;
+            break;
+        }
+        let (id, offer) = entry?;
+        last_scanned = Some(id);
+        let for_token = token_id.map_or(true, |token_id| offer.is_for(token_id));
+        let of_buyer = buyer.map_or(true, |buyer| offer.buyer == *buyer);
+        if for_token && of_buyer {
+            offers.push(offer);
+        }
+    }
+
+    // resume after the last offer gone through, even if it did not match
+    let next_start_after = match exhausted {
+        true => None,
+        false => last_scanned,
+    };
+    Ok(OffersResponse {
+        offers,
+        next_start_after,
+    })
+}
@@ -0,0 +1,347 @@
+use cosmwasm_std::{
+    Addr, Api, Attribute, BankMsg, Binary, Coin, CosmosMsg, Response, StdError, StdResult, Storage,
+    Uint128,
+};
+use cw_storage_plus::Item;
+use serde::{Deserialize, Serialize};
+
+use crate::msg::{FeeConfigMsg, RoyaltiesInfoResponse, RoyaltyConfigMsg};
+use crate::state::TokenMetadata;
+use crate::ContractError;
+
+/// Highest royalty, in percent of the sale price, a seat can carry
+pub const MAX_ROYALTY_PERCENTAGE: u64 = 25;
+/// Highest platform fee, in basis points of the sale price
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+/// Platform fee taken on primary and secondary sales
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct FeeConfig {
+    /// The only address allowed to update the fee, distinct from the owner
+    pub admin: Addr,
+    pub fee_bps: u16,
+    pub collector: Addr,
+}
+
+pub const FEE_CONFIG: Item<FeeConfig> = Item::new("fee_config");
+
+impl FeeConfig {
+    pub fn new(api: &dyn Api, msg: FeeConfigMsg) -> Result<Self, ContractError> {
+        if msg.fee_bps > MAX_FEE_BPS {
+            return Err(ContractError::InvalidFee {
+                reason: format!("fee cannot exceed {} basis points", MAX_FEE_BPS),
+            });
+        }
+        // any seat can carry the highest royalty
+        if !payouts_fit(MAX_ROYALTY_PERCENTAGE, msg.fee_bps) {
+            return Err(ContractError::InvalidFee {
+                reason: "fee and royalty cannot exceed the sale price".to_string(),
+            });
+        }
+        Ok(FeeConfig {
+            admin: api.addr_validate(&msg.admin)?,
+            fee_bps: msg.fee_bps,
+            collector: api.addr_validate(&msg.collector)?,
+        })
+    }
+
+    fn fee(&self, price: Uint128) -> Uint128 {
+        price.multiply_ratio(self.fee_bps, 10_000u128)
+    }
+}
+
+/// Royalty carried by the seats sold in the primary sale
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct RoyaltyConfig {
+    /// Percent of every secondary sale price
+    pub percentage: u64,
+    pub payment_address: Addr,
+}
+
+/// Seats sold in the primary sale carry no royalty while it is unset
+pub const ROYALTY_CONFIG: Item<RoyaltyConfig> = Item::new("royalty_config");
+
+impl RoyaltyConfig {
+    pub fn new(
+        storage: &dyn Storage,
+        api: &dyn Api,
+        msg: RoyaltyConfigMsg,
+    ) -> Result<Self, ContractError> {
+        validate_royalty(storage, msg.percentage)?;
+        Ok(RoyaltyConfig {
+            percentage: msg.percentage,
+            payment_address: api.addr_validate(&msg.payment_address)?,
+        })
+    }
+}
+
+/// Replaces the fee config, only the fee admin can call it
+pub fn update_fee_config(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    sender: &Addr,
+    admin: Option<String>,
+    fee_bps: Option<u16>,
+    collector: Option<String>,
+) -> Result<FeeConfig, ContractError> {
+    let config = FEE_CONFIG
+        .may_load(storage)?
+        .ok_or(ContractError::Unauthorized {})?;
+    if config.admin != *sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let config = FeeConfig::new(
+        api,
+        FeeConfigMsg {
+            admin: admin.unwrap_or_else(|| config.admin.to_string()),
+            fee_bps: fee_bps.unwrap_or(config.fee_bps),
+            collector: collector.unwrap_or_else(|| config.collector.to_string()),
+        },
+    )?;
+    FEE_CONFIG.save(storage, &config)?;
+    Ok(config)
+}
+
+/// Checks `funds` are a single coin of the denom of `price`, worth at least
+/// `price`, and returns the amount paid above it
+pub fn check_payment(funds: &[Coin], price: &Coin) -> Result<Uint128, ContractError> {
+    let paid = match funds {
+        [] => Coin {
+            denom: price.denom.clone(),
+            amount: Uint128::zero(),
+        },
+        [coin] if coin.denom == price.denom => coin.clone(),
+        coins => {
+            let denoms: Vec<&str> = coins.iter().map(|coin| coin.denom.as_str()).collect();
+            return Err(ContractError::WrongDenom {
+                expected: price.denom.clone(),
+                received: denoms.join(","),
+            });
+        }
+    };
+    if paid.amount < price.amount {
+        return Err(ContractError::InsufficientFunds {
+            expected: price.clone(),
+            received: paid,
+        });
+    }
+    Ok(paid.amount - price.amount)
+}
+
+/// Sends the `excess` of `denom` paid by `payer` back to them
+pub fn refund_excess(
+    response: Response<Binary>,
+    payer: &Addr,
+    denom: &str,
+    excess: Uint128,
+) -> Response<Binary> {
+    if excess.is_zero() {
+        return response;
+    }
+    response.add_message(BankMsg::Send {
+        to_address: payer.to_string(),
+        amount: vec![Coin {
+            denom: denom.to_string(),
+            amount: excess,
+        }],
+    })
+}
+
+/// How the price of a secondary sale is paid out
+pub struct SaleSplit {
+    pub seller: Coin,
+    pub royalty: Option<(Addr, Coin)>,
+    pub fee: Option<(Addr, Coin)>,
+}
+
+impl SaleSplit {
+    pub fn into_msgs(self, seller: &Addr) -> Vec<BankMsg> {
+        let mut payouts = vec![(seller.clone(), self.seller)];
+        payouts.extend(self.royalty);
+        payouts.extend(self.fee);
+        payouts
+            .into_iter()
+            .filter(|(_, coin)| !coin.amount.is_zero())
+            .map(|(to, coin)| BankMsg::Send {
+                to_address: to.to_string(),
+                amount: vec![coin],
+            })
+            .collect()
+    }
+
+    /// Breakdown of the payout into fee, royalty and seller proceeds
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let paid = |payout: &Option<(Addr, Coin)>| {
+            coins_attribute(payout.iter().map(|(_, coin)| coin.clone()).collect())
+        };
+        vec![
+            Attribute::new("fee", paid(&self.fee)),
+            Attribute::new("royalty", paid(&self.royalty)),
+            Attribute::new(
+                "seller_proceeds",
+                coins_attribute(vec![self.seller.clone()]),
+            ),
+        ]
+    }
+}
+
+/// Errors unless the royalty of a seat is bounded and paid to a valid address
+pub fn validate_royalties(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    extension: &TokenMetadata,
+) -> Result<(), ContractError> {
+    let percentage = extension.royalty_percentage.unwrap_or_default();
+    validate_royalty(storage, percentage)?;
+    match royalty_address(extension) {
+        Some(address) => {
+            api.addr_validate(address)?;
+        }
+        None if percentage > 0 => {
+            return Err(ContractError::InvalidRoyalty {
+                reason: "a payment address is required".to_string(),
+            })
+        }
+        None => {}
+    }
+    Ok(())
+}
+
+/// Errors unless `percentage` is bounded and leaves room for the platform fee
+fn validate_royalty(storage: &dyn Storage, percentage: u64) -> Result<(), ContractError> {
+    if percentage > MAX_ROYALTY_PERCENTAGE {
+        return Err(ContractError::InvalidRoyalty {
+            reason: format!("percentage cannot exceed {}", MAX_ROYALTY_PERCENTAGE),
+        });
+    }
+    let fee_bps = FEE_CONFIG
+        .may_load(storage)?
+        .map(|config| config.fee_bps)
+        .unwrap_or_default();
+    if !payouts_fit(percentage, fee_bps) {
+        return Err(ContractError::InvalidRoyalty {
+            reason: "royalty and fee cannot exceed the sale price".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Whether a royalty of `percentage` and a fee of `fee_bps` can both be paid
+/// out of a sale price
+fn payouts_fit(percentage: u64, fee_bps: u16) -> bool {
+    percentage * 100 + u64::from(fee_bps) <= 10_000
+}
+
+/// Splits `price` between the platform fee, the royalty address of the seat
+/// and the seller
+pub fn split_sale(
+    storage: &dyn Storage,
+    extension: &TokenMetadata,
+    price: &Coin,
+) -> StdResult<SaleSplit> {
+    let coin = |amount: Uint128| Coin {
+        denom: price.denom.clone(),
+        amount,
+    };
+    let royalty_amount = royalty_amount(extension, price.amount);
+    let royalty =
+        royalty_address(extension).map(|address| (Addr::unchecked(address), coin(royalty_amount)));
+    let fee = FEE_CONFIG
+        .may_load(storage)?
+        .map(|config| (config.collector.clone(), coin(config.fee(price.amount))));
+    let fee_amount = fee.as_ref().map(|(_, fee)| fee.amount).unwrap_or_default();
+    let seller_amount = price
+        .amount
+        .checked_sub(royalty_amount)?
+        .checked_sub(fee_amount)
+        .map_err(|_| StdError::generic_err("Fee and royalty exceed the sale price"))?;
+    Ok(SaleSplit {
+        seller: coin(seller_amount),
+        royalty,
+        fee,
+    })
+}
+
+/// Takes the platform fee out of every payout of a primary sale and sends it
+/// to the fee collector
+pub fn take_primary_sale_fee(
+    storage: &dyn Storage,
+    mut response: Response<Binary>,
+) -> Result<Response<Binary>, ContractError> {
+    let fee_config = FEE_CONFIG.may_load(storage)?;
+    let mut fees = vec![];
+    let mut proceeds = vec![];
+    for sub_msg in response.messages.iter_mut() {
+        if let CosmosMsg::Bank(BankMsg::Send { amount, .. }) = &mut sub_msg.msg {
+            for coin in amount.iter_mut() {
+                let fee = match &fee_config {
+                    Some(config) => config.fee(coin.amount),
+                    None => Uint128::zero(),
+                };
+                coin.amount = coin.amount.checked_sub(fee).map_err(StdError::from)?;
+                if !fee.is_zero() {
+                    fees.push(Coin {
+                        denom: coin.denom.clone(),
+                        amount: fee,
+                    });
+                }
+                proceeds.push(coin.clone());
+            }
+            amount.retain(|coin| !coin.amount.is_zero());
+        }
+    }
+    response.messages.retain(|sub_msg| {
+        !matches!(&sub_msg.msg, CosmosMsg::Bank(BankMsg::Send { amount, .. }) if amount.is_empty())
+    });
+
+    let attributes = vec![
+        Attribute::new("fee", coins_attribute(fees.clone())),
+        Attribute::new("royalty", coins_attribute(vec![])),
+        Attribute::new("seller_proceeds", coins_attribute(proceeds)),
+    ];
+    if let Some(config) = fee_config {
+        if !fees.is_empty() {
+            response = response.add_message(BankMsg::Send {
+                to_address: config.collector.to_string(),
+                amount: fees,
+            });
+        }
+    }
+    Ok(response.add_attributes(attributes))
+}
+
+/// CW-2981 royalty owed when the seat is sold for `sale_price`
+pub fn royalty_info(extension: &TokenMetadata, sale_price: Uint128) -> RoyaltiesInfoResponse {
+    RoyaltiesInfoResponse {
+        address: royalty_address(extension).unwrap_or_default().to_string(),
+        royalty_amount: royalty_amount(extension, sale_price),
+    }
+}
+
+/// Royalty address of a seat, an empty address counts as none
+fn royalty_address(extension: &TokenMetadata) -> Option<&str> {
+    extension
+        .royalty_payment_address
+        .as_deref()
+        .filter(|address| !address.is_empty())
+}
+
+fn royalty_amount(extension: &TokenMetadata, sale_price: Uint128) -> Uint128 {
+    match (royalty_address(extension), extension.royalty_percentage) {
+        (Some(_), Some(percentage)) => sale_price.multiply_ratio(percentage, 100u64),
+        _ => Uint128::zero(),
+    }
+}
+
+/// Comma separated coins, "0" when nothing is paid
+fn coins_attribute(coins: Vec<Coin>) -> String {
+    let coins: Vec<String> = coins
+        .iter()
+        .filter(|coin| !coin.amount.is_zero())
+        .map(Coin::to_string)
+        .collect();
+    if coins.is_empty() {
+        return "0".to_string();
+    }
+    coins.join(",")
+}
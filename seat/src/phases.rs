@@ -0,0 +1,291 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Api, Empty, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Item, Map};
+use sha2::{Digest, Sha256};
+
+use crate::msg::{AllowlistMsg, EligibilityResponse, SalePhaseMsg};
+use crate::ContractError;
+
+#[cw_serde]
+pub enum Allowlist {
+    /// The addresses stored in `ALLOWLISTS` for the phase
+    Addresses {},
+    /// Hex encoded root of a Merkle tree whose leaves are the sha256 hashes
+    /// of the allowed addresses
+    MerkleRoot(String),
+}
+
+/// A phase of the primary sale, e.g. an allowlist presale then a public sale
+#[cw_serde]
+pub struct SalePhase {
+    pub id: String,
+    pub start: Timestamp,
+    /// The phase lasts until the next one starts if `None`
+    pub end: Option<Timestamp>,
+    /// Anyone can buy during the phase if `None`
+    pub allowlist: Option<Allowlist>,
+    /// Seats an address can buy during the phase, unlimited if `None`
+    pub mint_limit: Option<u32>,
+}
+
+impl SalePhase {
+    fn is_active(&self, next: Option<&SalePhase>, now: Timestamp) -> bool {
+        let end = self.end.or_else(|| next.map(|next| next.start));
+        self.start <= now && end.map_or(true, |end| now < end)
+    }
+}
+
+/// Phases of the primary sale ordered by start. Primary sales are open to
+/// anyone while it is empty.
+pub const SALE_PHASES: Item<Vec<SalePhase>> = Item::new("sale_phases");
+
+/// Addresses allowed to buy during a phase, by phase id
+pub const ALLOWLISTS: Map<(&str, &Addr), Empty> = Map::new("allowlists");
+
+/// Seats an address bought during a phase, by phase id
+pub const PHASE_MINTS: Map<(&str, &Addr), u32> = Map::new("phase_mints");
+
+/// Replaces the phases of the primary sale along with their allowlists and
+/// mint counts
+pub fn set_sale_phases(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    phases: Vec<SalePhaseMsg>,
+) -> Result<Vec<SalePhase>, ContractError> {
+    for phase in SALE_PHASES.may_load(storage)?.unwrap_or_default() {
+        clear_phase(storage, &phase.id)?;
+    }
+
+    let mut sale_phases: Vec<SalePhase> = vec![];
+    for msg in phases {
+        let invalid = |reason: String| ContractError::InvalidSalePhase {
+            phase_id: msg.id.clone(),
+            reason,
+        };
+        if msg.id.trim().is_empty() {
+            return Err(invalid("phase id cannot be empty".to_string()));
+        }
+        if sale_phases.iter().any(|phase| phase.id == msg.id) {
+            return Err(invalid("phase ids must be unique".to_string()));
+        }
+        if msg.end.is_some_and(|end| end <= msg.start) {
+            return Err(invalid("phases must end after they start".to_string()));
+        }
+        if let Some(previous) = sale_phases.last() {
+            let previous_end = previous.end.unwrap_or(previous.start);
+            if msg.start <= previous.start || msg.start < previous_end {
+                return Err(invalid(
+                    "phases must be ordered by start and cannot overlap".to_string(),
+                ));
+            }
+        }
+        let allowlist = match msg.allowlist {
+            None => None,
+            Some(AllowlistMsg::Addresses(addresses)) => {
+                for address in addresses {
+                    let address = api.addr_validate(&address)?;
+                    ALLOWLISTS.save(storage, (&msg.id, &address), &Empty {})?;
+                }
+                Some(Allowlist::Addresses {})
+            }
+            Some(AllowlistMsg::MerkleRoot(root)) => {
+                decode_hash(&root).ok_or_else(|| invalid("invalid Merkle root".to_string()))?;
+                Some(Allowlist::MerkleRoot(root))
+            }
+        };
+        sale_phases.push(SalePhase {
+            id: msg.id,
+            start: msg.start,
+            end: msg.end,
+            allowlist,
+            mint_limit: msg.mint_limit,
+        });
+    }
+    SALE_PHASES.save(storage, &sale_phases)?;
+    Ok(sale_phases)
+}
+
+/// Adds and removes addresses of the allowlist of the phase `phase_id`
+pub fn update_allowlist(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    phase_id: &str,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<(), ContractError> {
+    let phase = load_sale_phase(storage, phase_id)?;
+    if phase.allowlist != Some(Allowlist::Addresses {}) {
+        return Err(ContractError::InvalidSalePhase {
+            phase_id: phase_id.to_string(),
+            reason: "the phase has no address allowlist".to_string(),
+        });
+    }
+    for address in add {
+        let address = api.addr_validate(&address)?;
+        ALLOWLISTS.save(storage, (phase_id, &address), &Empty {})?;
+    }
+    for address in remove {
+        let address = api.addr_validate(&address)?;
+        ALLOWLISTS.remove(storage, (phase_id, &address));
+    }
+    Ok(())
+}
+
+fn load_sale_phase(storage: &dyn Storage, phase_id: &str) -> Result<SalePhase, ContractError> {
+    SALE_PHASES
+        .may_load(storage)?
+        .unwrap_or_default()
+        .into_iter()
+        .find(|phase| phase.id == phase_id)
+        .ok_or_else(|| ContractError::InvalidSalePhase {
+            phase_id: phase_id.to_string(),
+            reason: "no such phase".to_string(),
+        })
+}
+
+/// Removes the allowlist and the mint counts of the phase `phase_id`, so a
+/// phase reusing its id starts afresh
+fn clear_phase(storage: &mut dyn Storage, phase_id: &str) -> StdResult<()> {
+    let addresses = ALLOWLISTS
+        .prefix(phase_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for address in addresses {
+        ALLOWLISTS.remove(storage, (phase_id, &address));
+    }
+    let buyers = PHASE_MINTS
+        .prefix(phase_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for buyer in buyers {
+        PHASE_MINTS.remove(storage, (phase_id, &buyer));
+    }
+    Ok(())
+}
+
+/// The phase of the primary sale at `now`, `None` if the sale has no phases.
+/// Errors if the sale has phases but none is active.
+pub fn active_sale_phase(
+    storage: &dyn Storage,
+    now: Timestamp,
+) -> Result<Option<SalePhase>, ContractError> {
+    let phases = SALE_PHASES.may_load(storage)?.unwrap_or_default();
+    if phases.is_empty() {
+        return Ok(None);
+    }
+    phases
+        .iter()
+        .enumerate()
+        .find(|(i, phase)| phase.is_active(phases.get(i + 1), now))
+        .map(|(_, phase)| Some(phase.clone()))
+        .ok_or(ContractError::SaleNotActive {})
+}
+
+/// Counts a seat `buyer` is about to buy against the limit of the active
+/// phase, after checking `buyer` is allowed in it
+pub fn record_phase_mint(
+    storage: &mut dyn Storage,
+    now: Timestamp,
+    buyer: &Addr,
+    proof: &[String],
+) -> Result<(), ContractError> {
+    let phase = match active_sale_phase(storage, now)? {
+        Some(phase) => phase,
+        None => return Ok(()),
+    };
+    if !is_allowed(storage, &phase, buyer, proof)? {
+        return Err(ContractError::NotAllowlisted {
+            address: buyer.to_string(),
+        });
+    }
+    let minted = PHASE_MINTS
+        .may_load(storage, (&phase.id, buyer))?
+        .unwrap_or_default();
+    if phase.mint_limit.is_some_and(|limit| minted >= limit) {
+        return Err(ContractError::PhaseMintLimitReached { phase_id: phase.id });
+    }
+    PHASE_MINTS.save(storage, (&phase.id, buyer), &(minted + 1))?;
+    Ok(())
+}
+
+/// Whether `address` can buy seats at `now`, and how many it bought in the
+/// active phase
+pub fn eligibility(
+    storage: &dyn Storage,
+    now: Timestamp,
+    address: &Addr,
+    proof: &[String],
+) -> Result<EligibilityResponse, ContractError> {
+    let phase = match active_sale_phase(storage, now) {
+        Ok(Some(phase)) => phase,
+        Ok(None) => {
+            return Ok(EligibilityResponse {
+                phase_id: None,
+                eligible: true,
+                minted: 0,
+                mint_limit: None,
+            })
+        }
+        Err(ContractError::SaleNotActive {}) => {
+            return Ok(EligibilityResponse {
+                phase_id: None,
+                eligible: false,
+                minted: 0,
+                mint_limit: None,
+            })
+        }
+        Err(err) => return Err(err),
+    };
+    let minted = PHASE_MINTS
+        .may_load(storage, (&phase.id, address))?
+        .unwrap_or_default();
+    let eligible = is_allowed(storage, &phase, address, proof)?
+        && phase.mint_limit.map_or(true, |limit| minted < limit);
+    Ok(EligibilityResponse {
+        phase_id: Some(phase.id),
+        eligible,
+        minted,
+        mint_limit: phase.mint_limit,
+    })
+}
+
+fn is_allowed(
+    storage: &dyn Storage,
+    phase: &SalePhase,
+    address: &Addr,
+    proof: &[String],
+) -> Result<bool, ContractError> {
+    match &phase.allowlist {
+        None => Ok(true),
+        Some(Allowlist::Addresses {}) => Ok(ALLOWLISTS.has(storage, (&phase.id, address))),
+        Some(Allowlist::MerkleRoot(root)) => verify_merkle_proof(root, address, proof),
+    }
+}
+
+/// Checks `proof` leads from the hash of `address` to `root`. Pairs of hashes
+/// are sorted before being hashed together.
+fn verify_merkle_proof(
+    root: &str,
+    address: &Addr,
+    proof: &[String],
+) -> Result<bool, ContractError> {
+    let mut hash: [u8; 32] = Sha256::digest(address.as_bytes()).into();
+    for step in proof {
+        let step = decode_hash(step).ok_or(ContractError::InvalidMerkleProof {})?;
+        let (first, second) = if hash <= step {
+            (hash, step)
+        } else {
+            (step, hash)
+        };
+        hash = Sha256::new()
+            .chain_update(first)
+            .chain_update(second)
+            .finalize()
+            .into();
+    }
+    Ok(decode_hash(root) == Some(hash))
+}
+
+fn decode_hash(hash: &str) -> Option<[u8; 32]> {
+    hex::decode(hash).ok()?.try_into().ok()
+}
@@ -0,0 +1,66 @@
+use cosmwasm_std::{Addr, Api, Coin, StdResult, Storage, Timestamp};
+use cw_storage_plus::Item;
+use serde::{Deserialize, Serialize};
+
+use crate::msg::RenewalConfigMsg;
+use crate::state::{TokenMetadata, PAYMENT_DENOMS};
+use crate::ContractError;
+
+/// Price and length of a seat renewal
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct RenewalConfig {
+    pub price: Coin,
+    /// Seconds a renewal adds to the expiry of a seat
+    pub duration: u64,
+    /// Receives the renewal payments
+    pub recipient: Addr,
+}
+
+/// Seats can only be renewed while it is set
+pub const RENEWAL_CONFIG: Item<RenewalConfig> = Item::new("renewal_config");
+
+impl RenewalConfig {
+    pub fn new(
+        storage: &dyn Storage,
+        api: &dyn Api,
+        msg: RenewalConfigMsg,
+    ) -> Result<Self, ContractError> {
+        if msg.duration == 0 || msg.price.amount.is_zero() {
+            return Err(ContractError::InvalidRenewal {
+                reason: "price and duration cannot be zero".to_string(),
+            });
+        }
+        if !PAYMENT_DENOMS.load(storage)?.contains(&msg.price.denom) {
+            return Err(ContractError::InvalidRenewal {
+                reason: format!("{} is not a payment denom", msg.price.denom),
+            });
+        }
+        Ok(RenewalConfig {
+            price: msg.price,
+            duration: msg.duration,
+            recipient: api.addr_validate(&msg.recipient)?,
+        })
+    }
+
+    /// Expiry of a seat expiring at `expires_at` once renewed at `now`.
+    /// Expired seats are renewed from `now`.
+    pub fn renewed_expiry(&self, expires_at: Timestamp, now: Timestamp) -> Timestamp {
+        expires_at.max(now).plus_seconds(self.duration)
+    }
+}
+
+/// Expiry of a seat sold in the primary sale at `now`: one renewal duration
+/// from now while renewals are configured, perpetual otherwise
+pub fn primary_sale_expiry(storage: &dyn Storage, now: Timestamp) -> StdResult<Option<Timestamp>> {
+    Ok(RENEWAL_CONFIG
+        .may_load(storage)?
+        .map(|config| now.plus_seconds(config.duration)))
+}
+
+/// Perpetual seats are always active, others until they expire
+pub fn is_active(extension: &TokenMetadata, now: &Timestamp) -> bool {
+    match extension.expires_at {
+        Some(expires_at) => expires_at > *now,
+        None => true,
+    }
+}
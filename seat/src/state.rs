@@ -1,18 +1,41 @@
 use cosmwasm_schema::cw_serde;
 
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, cmp::Ordering, rc::Rc};
 
 use burnt_glue::module::Module;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, BondedDenomResponse, Coin, Deps, DepsMut, Empty, Env, MessageInfo,
-    Order, QueryRequest, Response, StakingQuery, StdResult,
+    from_binary, to_binary, Addr, Api, BankMsg, Binary, Coin, Deps, DepsMut, Empty, Env,
+    MessageInfo, Order, Reply, Response, StdError, StdResult, Storage, SubMsgResult, Timestamp,
 };
-use cw_storage_plus::{Item, Map};
+use cw20::Cw20ReceiveMsg;
+use cw721_base::state::TokenInfo;
+use cw721_base::MintMsg;
+use cw_storage_plus::{Bound, Item, Map};
+use migrations::module_query_error;
 use ownable::Ownable;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use token::Tokens;
 
-use crate::msg::SeatInfo;
+use crate::assets::{cw20_denom, validate_denom, PaymentAsset, CW20_PREFIX};
+use crate::auctions::{Auction, AuctionKind, Bid, AUCTIONS};
+use crate::listings::{listing_expired, LISTING_EXPIRIES};
+use crate::migrate::dry_run_migration;
+use crate::msg::{
+    AllSeatsResponse, AuctionInfo, AuctionsResponse, HubQueryMsg, ListingMsg, SeatFilters,
+    SeatInfo, SubscriptionInfo, SubscriptionsResponse,
+};
+use crate::offers::{list_offers, load_offer, make_offer, Offer, OFFERS};
+use crate::payments::{
+    check_payment, refund_excess, royalty_info, split_sale, take_primary_sale_fee,
+    update_fee_config, validate_royalties, RoyaltyConfig, FEE_CONFIG, ROYALTY_CONFIG,
+};
+use crate::phases::{self, SalePhase, SALE_PHASES};
+use crate::renewals::{is_active, primary_sale_expiry, RenewalConfig, RENEWAL_CONFIG};
+use crate::subscriptions::{
+    expiring_subscriptions, remove_subscription, save_subscription, SubscriptionConfig,
+    SUBSCRIPTIONS, SUBSCRIPTION_CONFIG,
+};
+use crate::tiers;
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     ContractError,
@@ -56,6 +79,10 @@ pub struct TokenMetadata {
     /// as the minter addr
     /// question: how do we validate this?
     pub royalty_payment_address: Option<String>,
+    /// Id of the tier the seat belongs to, see `tiers::Tier`
+    pub tier: Option<String>,
+    /// The seat is inactive from then on, perpetual if `None`
+    pub expires_at: Option<Timestamp>,
 }
 
 pub struct SeatModules<'a, T, U>
@@ -72,18 +99,74 @@ where
 }
 
 pub const HUB_CONTRACT: Item<Addr> = Item::new("hub_contract");
+/// Whether seats can change hands, unless their tier says otherwise
+pub const TRANSFERABLE: Item<bool> = Item::new("transferable");
+/// Denoms seats can be paid with, the first one is used by the token module
+pub const PAYMENT_DENOMS: Item<Vec<String>> = Item::new("payment_denoms");
+
+/// Errors unless `denoms` is a non-empty list of distinct, non-empty denoms,
+/// the first of which is native since the seat token module charges in it
+pub fn validate_payment_denoms(api: &dyn Api, denoms: &[String]) -> Result<(), ContractError> {
+    match denoms.first() {
+        None => {
+            return Err(ContractError::InvalidPaymentDenoms {
+                reason: "at least one denom is required".to_string(),
+            })
+        }
+        Some(denom) if denom.starts_with(CW20_PREFIX) => {
+            return Err(ContractError::InvalidPaymentDenoms {
+                reason: "the first denom must be native".to_string(),
+            })
+        }
+        Some(_) => {}
+    }
+    for (i, denom) in denoms.iter().enumerate() {
+        if denom.trim().is_empty() {
+            return Err(ContractError::InvalidPaymentDenoms {
+                reason: "denoms cannot be empty".to_string(),
+            });
+        }
+        validate_denom(api, denom).map_err(|_| ContractError::InvalidPaymentDenoms {
+            reason: format!("invalid CW20 token in {}", denom),
+        })?;
+        if denoms[..i].contains(denom) {
+            return Err(ContractError::InvalidPaymentDenoms {
+                reason: format!("duplicate denom {}", denom),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub(crate) const DEFAULT_LIMIT: u32 = 10;
+pub(crate) const MAX_LIMIT: u32 = 30;
+/// Most seats `AllSeats` goes through per page, however few match its filters
+const MAX_SCANNED_SEATS: usize = 100;
+/// Reply ids reserved per sender of sub-messages, the contract first then
+/// the modules in `REPLY_SENDERS` order
+pub const REPLY_ID_RANGE: u64 = 1_000;
+const REPLY_SENDERS: [&str; 7] = [
+    "seat",
+    "ownable",
+    "metadata",
+    "seat_token",
+    "redeemable",
+    "sellable",
+    "sales",
+];
+
+/// The contract or module whose sub-message the reply `id` answers
+pub fn reply_sender(id: u64) -> Option<&'static str> {
+    REPLY_SENDERS.get((id / REPLY_ID_RANGE) as usize).copied()
+}
 
 impl<'a> SeatModules<'a, SeatMetadata, TokenMetadata> {
-    pub fn new(deps: Deps) -> Self {
-        // query for bond denom
-        let bond_denom_request = QueryRequest::Staking(StakingQuery::BondedDenom {});
-        // throw if this fails
-        let bond_denom_resp: BondedDenomResponse = deps
-            .querier
-            .query(&bond_denom_request)
-            .map_err(ContractError::from)
-            .unwrap();
-        let bond_denom = bond_denom_resp.denom;
+    pub fn new(deps: Deps) -> StdResult<Self> {
+        // the token module charges in the first accepted payment denom, which
+        // is unset until the contract is instantiated
+        let payment_denom = PAYMENT_DENOMS
+            .may_load(deps.storage)?
+            .and_then(|denoms| denoms.into_iter().next());
         // instantiate all modules
 
         // ownable module
@@ -98,7 +181,7 @@ impl<'a> SeatModules<'a, SeatMetadata, TokenMetadata> {
         // Burnt token module
         let seat_token = Tokens::<TokenMetadata, Empty, Empty, Empty>::new(
             cw721_base::Cw721Contract::default(),
-            Some(bond_denom),
+            payment_denom,
         );
         let borrowable_seat_token = Rc::new(RefCell::new(seat_token));
         // Redeemable token
@@ -116,14 +199,14 @@ impl<'a> SeatModules<'a, SeatMetadata, TokenMetadata> {
             Item::new("primary_sales"),
         );
 
-        SeatModules {
+        Ok(SeatModules {
             ownable: borrowable_ownable,
             metadata,
             seat_token: borrowable_seat_token,
             redeemable,
             sellable_token: borrowable_sellable_token,
             sales,
-        }
+        })
     }
 
     pub fn instantiate(
@@ -152,6 +235,11 @@ impl<'a> SeatModules<'a, SeatMetadata, TokenMetadata> {
             .instantiate(&mut mut_deps.branch(), &env, &info, msg.seat_token.clone())
             .map_err(ContractError::SeatTokenError)?;
 
+        // Redeemable module
+        self.redeemable
+            .instantiate(&mut mut_deps.branch(), &env, &info, msg.redeemable.clone())
+            .map_err(ContractError::RedeemableError)?;
+
         self.sales
             .instantiate(&mut mut_deps.branch(), &env, &info, msg.sales.clone())
             .map_err(ContractError::SalesError)?;
@@ -185,25 +273,106 @@ impl<'a> SeatModules<'a, SeatMetadata, TokenMetadata> {
         env: Env,
         info: MessageInfo,
         msg: ExecuteMsg,
+    ) -> Result<Response<Binary>, ContractError> {
+        // CW20 denoms are only paid through `receive_cw20`, a native coin
+        // named after one would be paid out of the contract's CW20 balance
+        if let Some(coin) = info
+            .funds
+            .iter()
+            .find(|coin| coin.denom.starts_with(CW20_PREFIX))
+        {
+            return Err(ContractError::UnsupportedPaymentAsset {
+                denom: coin.denom.clone(),
+            });
+        }
+        self.dispatch(deps, env, info, msg)
+    }
+
+    fn dispatch(
+        &mut self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
     ) -> Result<Response<Binary>, ContractError> {
         let mut mut_deps = Box::new(deps);
         let result = match msg {
-            ExecuteMsg::Ownable(msg) => self
-                .ownable
-                .borrow_mut()
-                .execute(&mut mut_deps, env, info, msg)
-                .map_err(ContractError::OwnableError),
+            ExecuteMsg::Ownable(msg) => {
+                // the owner must not be able to waive the platform fee
+                if let ownable::ExecuteMsg::SetOwner(owner) = &msg {
+                    let owner = mut_deps.api.addr_validate(owner)?;
+                    if FEE_CONFIG
+                        .may_load(mut_deps.storage)?
+                        .is_some_and(|config| config.admin == owner)
+                    {
+                        return Err(ContractError::FeeAdminIsOwner {});
+                    }
+                }
+                self.ownable
+                    .borrow_mut()
+                    .execute(&mut mut_deps, env, info, msg)
+                    .map_err(ContractError::OwnableError)
+            }
 
             ExecuteMsg::Metadata(msg) => self
                 .metadata
                 .execute(&mut mut_deps, env, info, msg)
                 .map_err(ContractError::MetadataError),
 
-            ExecuteMsg::SeatToken(msg) => self
-                .seat_token
-                .borrow_mut()
-                .execute(&mut mut_deps, env, info, msg)
-                .map_err(ContractError::SeatTokenError),
+            ExecuteMsg::SeatToken(msg) => {
+                match &msg {
+                    cw721_base::ExecuteMsg::Mint(mint_msg) => {
+                        validate_royalties(mut_deps.storage, mut_deps.api, &mint_msg.extension)?;
+                        record_tier_mint(mut_deps.storage, &mint_msg.extension)?;
+                    }
+                    // listings do not survive a change of owner
+                    cw721_base::ExecuteMsg::TransferNft { token_id, .. }
+                    | cw721_base::ExecuteMsg::SendNft { token_id, .. } => {
+                        self.ensure_transferable(mut_deps.as_ref(), token_id)?;
+                        self.delist(mut_deps.storage, token_id);
+                    }
+                    cw721_base::ExecuteMsg::Burn { token_id } => {
+                        ensure_not_auctioned(mut_deps.storage, token_id)?;
+                        remove_subscription(mut_deps.storage, token_id)?;
+                        self.delist(mut_deps.storage, token_id);
+                    }
+                    _ => {}
+                }
+                self.seat_token
+                    .borrow_mut()
+                    .execute(&mut mut_deps, env, info, msg)
+                    .map_err(ContractError::SeatTokenError)
+            }
+
+            ExecuteMsg::Redeemable(msg) => {
+                // benefits of a seat can only be redeemed by its current owner
+                match &msg {
+                    redeemable::ExecuteMsg::RedeemItem(token_id) => {
+                        self.ensure_seat_owner(mut_deps.as_ref(), token_id, &info.sender)?;
+                        self.ensure_active(mut_deps.as_ref(), &env, token_id)?;
+                    }
+                }
+                self.redeemable
+                    .execute(&mut mut_deps, env, info, msg)
+                    .map_err(ContractError::RedeemableError)
+            }
+
+            // secondary sales are settled here so fees and royalties are paid out
+            ExecuteMsg::Sellable(sellable::msg::ExecuteMsg::BuyToken { token_id }) => {
+                return self.buy_token(*mut_deps, &env, &info, token_id)
+            }
+
+            ExecuteMsg::Sellable(sellable::msg::ExecuteMsg::List { listings }) => {
+                let listings = listings
+                    .into_iter()
+                    .map(|(token_id, price)| ListingMsg {
+                        token_id,
+                        price,
+                        expires_at: None,
+                    })
+                    .collect();
+                return self.list(&mut mut_deps, env, info, listings);
+            }
 
             ExecuteMsg::Sellable(msg) => self
                 .sellable_token
@@ -211,61 +380,1546 @@ impl<'a> SeatModules<'a, SeatMetadata, TokenMetadata> {
                 .execute(&mut mut_deps, env, info, msg)
                 .map_err(ContractError::SellableError),
 
-            ExecuteMsg::Sales(msg) => self
-                .sales
-                .execute(&mut mut_deps, env, info, msg)
-                .map_err(ContractError::SalesError),
+            ExecuteMsg::ListSeats { listings } => {
+                return self.list(&mut mut_deps, env, info, listings)
+            }
+
+            ExecuteMsg::UpdatePrices { prices } => {
+                let mut listings = vec![];
+                for (token_id, price) in prices {
+                    self.ensure_listed(mut_deps.as_ref(), &env, &token_id)?;
+                    let expires_at = LISTING_EXPIRIES.may_load(mut_deps.storage, &token_id)?;
+                    listings.push(ListingMsg {
+                        token_id,
+                        price,
+                        expires_at,
+                    });
+                }
+                return self.list(&mut mut_deps, env, info, listings);
+            }
+
+            ExecuteMsg::DelistAll {} => return self.delist_all(*mut_deps, &info),
+
+            ExecuteMsg::Sales(sales::msg::ExecuteMsg::BuyItem(mint_msg)) => {
+                return self.buy_item(&mut mut_deps, env, info, mint_msg, &[])
+            }
+
+            ExecuteMsg::BuyAllowlisted { proof, mint_msg } => {
+                return self.buy_item(&mut mut_deps, env, info, mint_msg, &proof)
+            }
+
+            ExecuteMsg::Sales(msg) => {
+                // primary sales are only priced in payment denoms
+                if let sales::msg::ExecuteMsg::PrimarySale(sale) = &msg {
+                    let payment_denoms = PAYMENT_DENOMS.load(mut_deps.storage)?;
+                    if let Some(price) = sale
+                        .price
+                        .iter()
+                        .find(|price| !payment_denoms.contains(&price.denom))
+                    {
+                        return Err(ContractError::UnsupportedPaymentAsset {
+                            denom: price.denom.clone(),
+                        });
+                    }
+                }
+                self.sales
+                    .execute(&mut mut_deps, env, info, msg)
+                    .map_err(ContractError::SalesError)
+            }
+
+            ExecuteMsg::StartAuction {
+                token_id,
+                denom,
+                kind,
+                start,
+                end,
+            } => {
+                return self
+                    .start_auction(*mut_deps, &env, &info, token_id, denom, kind, start, end)
+            }
+
+            ExecuteMsg::BidAuction { token_id } => {
+                return self.bid_auction(*mut_deps, &env, &info, token_id)
+            }
+
+            ExecuteMsg::BuyAuction { token_id } => {
+                return self.buy_auction(*mut_deps, &env, &info, token_id)
+            }
+
+            ExecuteMsg::SettleAuction { token_id } => {
+                return self.settle_auction(*mut_deps, &env, token_id)
+            }
+
+            ExecuteMsg::CancelAuction { token_id } => {
+                return self.cancel_auction(*mut_deps, &info, token_id)
+            }
+
+            ExecuteMsg::MakeOffer {
+                token_id,
+                expires_at,
+            } => return self.make_offer(*mut_deps, &env, &info, token_id, expires_at),
+
+            ExecuteMsg::AcceptOffer { offer_id, token_id } => {
+                return self.accept_offer(*mut_deps, &env, &info, offer_id, token_id)
+            }
+
+            ExecuteMsg::RejectOffer { offer_id } => {
+                return self.reject_offer(*mut_deps, &info, offer_id)
+            }
+
+            ExecuteMsg::CancelOffer { offer_id } => {
+                return self.cancel_offer(*mut_deps, &env, &info, offer_id)
+            }
+
+            ExecuteMsg::SetSalePhases { phases } => {
+                self.ensure_owner(mut_deps.as_ref(), &env, &info.sender)?;
+                let phases = phases::set_sale_phases(mut_deps.storage, mut_deps.api, phases)?;
+                let phase_ids: Vec<String> = phases.into_iter().map(|phase| phase.id).collect();
+                return Ok(Response::new()
+                    .add_attribute("action", "set_sale_phases")
+                    .add_attribute("phases", phase_ids.join(",")));
+            }
+
+            ExecuteMsg::UpdateAllowlist {
+                phase_id,
+                add,
+                remove,
+            } => {
+                self.ensure_owner(mut_deps.as_ref(), &env, &info.sender)?;
+                phases::update_allowlist(mut_deps.storage, mut_deps.api, &phase_id, add, remove)?;
+                return Ok(Response::new()
+                    .add_attribute("action", "update_allowlist")
+                    .add_attribute("phase_id", phase_id));
+            }
+
+            ExecuteMsg::Receive(receive) => {
+                return self.receive_cw20(*mut_deps, env, info, receive)
+            }
+
+            ExecuteMsg::SetPaymentDenoms { denoms } => {
+                return self.set_payment_denoms(*mut_deps, &env, &info, denoms)
+            }
+
+            ExecuteMsg::SetTier { tier_id, tier } => {
+                self.ensure_owner(mut_deps.as_ref(), &env, &info.sender)?;
+                let tier = tiers::set_tier(mut_deps.storage, &tier_id, tier)?;
+                let response = Response::new()
+                    .add_attribute("action", "set_tier")
+                    .add_attribute("tier_id", tier_id)
+                    .add_attribute("name", tier.name);
+                return self.withdraw_soulbound_seats(*mut_deps, response);
+            }
+
+            ExecuteMsg::SetRoyaltyConfig { config } => {
+                self.ensure_owner(mut_deps.as_ref(), &env, &info.sender)?;
+                match config {
+                    Some(config) => {
+                        let config = RoyaltyConfig::new(mut_deps.storage, mut_deps.api, config)?;
+                        ROYALTY_CONFIG.save(mut_deps.storage, &config)?;
+                    }
+                    None => ROYALTY_CONFIG.remove(mut_deps.storage),
+                }
+                return Ok(Response::new().add_attribute("action", "set_royalty_config"));
+            }
+
+            ExecuteMsg::SetRenewalConfig { config } => {
+                self.ensure_owner(mut_deps.as_ref(), &env, &info.sender)?;
+                match config {
+                    Some(config) => {
+                        let config = RenewalConfig::new(mut_deps.storage, mut_deps.api, config)?;
+                        RENEWAL_CONFIG.save(mut_deps.storage, &config)?;
+                    }
+                    None => RENEWAL_CONFIG.remove(mut_deps.storage),
+                }
+                return Ok(Response::new().add_attribute("action", "set_renewal_config"));
+            }
+
+            ExecuteMsg::RenewSeat { token_id } => {
+                return self.renew_seat(*mut_deps, &env, &info, token_id)
+            }
+
+            ExecuteMsg::SetSubscriptionConfig { config } => {
+                self.ensure_owner(mut_deps.as_ref(), &env, &info.sender)?;
+                match config {
+                    Some(config) => {
+                        let config =
+                            SubscriptionConfig::new(mut_deps.storage, mut_deps.api, config)?;
+                        SUBSCRIPTION_CONFIG.save(mut_deps.storage, &config)?;
+                    }
+                    None => SUBSCRIPTION_CONFIG.remove(mut_deps.storage),
+                }
+                return Ok(Response::new().add_attribute("action", "set_subscription_config"));
+            }
+
+            ExecuteMsg::Subscribe { token_id, periods } => {
+                return self.subscribe(*mut_deps, &env, &info, token_id, periods)
+            }
+
+            ExecuteMsg::UpdateFeeConfig {
+                admin,
+                fee_bps,
+                collector,
+            } => {
+                if let Some(admin) = &admin {
+                    let admin = mut_deps.api.addr_validate(admin)?;
+                    if self.is_owner(mut_deps.as_ref(), &env, &admin)? {
+                        return Err(ContractError::FeeAdminIsOwner {});
+                    }
+                }
+                let config = update_fee_config(
+                    mut_deps.storage,
+                    mut_deps.api,
+                    &info.sender,
+                    admin,
+                    fee_bps,
+                    collector,
+                )?;
+                return Ok(Response::new()
+                    .add_attribute("action", "update_fee_config")
+                    .add_attribute("admin", config.admin)
+                    .add_attribute("fee_bps", config.fee_bps.to_string())
+                    .add_attribute("collector", config.collector));
+            }
+
+            ExecuteMsg::SetTransferable { transferable } => {
+                self.ensure_owner(mut_deps.as_ref(), &env, &info.sender)?;
+                TRANSFERABLE.save(mut_deps.storage, &transferable)?;
+                let response = Response::new()
+                    .add_attribute("action", "set_transferable")
+                    .add_attribute("transferable", transferable.to_string());
+                return self.withdraw_soulbound_seats(*mut_deps, response);
+            }
+
+            ExecuteMsg::RevokeSeat { token_id } => {
+                return self.revoke_seat(*mut_deps, &info, token_id)
+            }
+
+            ExecuteMsg::RemoveTier { tier_id } => {
+                self.ensure_owner(mut_deps.as_ref(), &env, &info.sender)?;
+                tiers::remove_tier(mut_deps.storage, &tier_id)?;
+                return Ok(Response::new()
+                    .add_attribute("action", "remove_tier")
+                    .add_attribute("tier_id", tier_id));
+            }
+
+            ExecuteMsg::SetSaleTier { tier_id } => {
+                self.ensure_owner(mut_deps.as_ref(), &env, &info.sender)?;
+                tiers::set_sale_tier(mut_deps.storage, tier_id.clone())?;
+                return Ok(Response::new()
+                    .add_attribute("action", "set_sale_tier")
+                    .add_attribute("tier_id", tier_id.unwrap_or_default()));
+            }
         };
         result.map(|r| r.response)
     }
 
-    pub fn query(&self, deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
-        match msg {
-            QueryMsg::Ownable(msg) => {
-                to_binary(&self.ownable.borrow().query(&deps, env, msg).unwrap())
+    /// Handles the result of a sub-message dispatched by one of the modules.
+    /// No module registers a reply handler yet, so successful results are
+    /// acknowledged and failures are surfaced as errors.
+    pub fn reply(
+        &mut self,
+        _deps: DepsMut,
+        _env: Env,
+        msg: Reply,
+    ) -> Result<Response<Binary>, ContractError> {
+        let sender = reply_sender(msg.id).ok_or(ContractError::UnknownReply { id: msg.id })?;
+        // burnt-glue modules have no reply hook, results of their
+        // sub-messages can only be acknowledged here
+        match msg.result {
+            SubMsgResult::Ok(_) => Ok(Response::new()
+                .add_attribute("action", "reply")
+                .add_attribute("reply_id", msg.id.to_string())
+                .add_attribute("sender", sender)),
+            SubMsgResult::Err(error) => Err(ContractError::SubMsgFailed { id: msg.id, error }),
+        }
+    }
+
+    /// Executes the message of a CW20 `Send` on behalf of the sender of the
+    /// tokens, paid with them
+    pub fn receive_cw20(
+        &mut self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        receive: Cw20ReceiveMsg,
+    ) -> Result<Response<Binary>, ContractError> {
+        let denom = cw20_denom(&info.sender);
+        if !PAYMENT_DENOMS.load(deps.storage)?.contains(&denom) {
+            return Err(ContractError::UnsupportedPaymentAsset { denom });
+        }
+        let msg: ExecuteMsg = from_binary(&receive.msg)?;
+        let payable = matches!(
+            msg,
+            ExecuteMsg::Sellable(sellable::msg::ExecuteMsg::BuyToken { .. })
+                | ExecuteMsg::Sales(sales::msg::ExecuteMsg::BuyItem(_))
+                | ExecuteMsg::BuyAllowlisted { .. }
+                | ExecuteMsg::RenewSeat { .. }
+                | ExecuteMsg::Subscribe { .. }
+                | ExecuteMsg::BidAuction { .. }
+                | ExecuteMsg::BuyAuction { .. }
+                | ExecuteMsg::MakeOffer { .. }
+        );
+        if !payable {
+            return Err(ContractError::UnsupportedMessage {});
+        }
+        let info = MessageInfo {
+            sender: deps.api.addr_validate(&receive.sender)?,
+            funds: vec![Coin {
+                denom,
+                amount: receive.amount,
+            }],
+        };
+        self.dispatch(deps, env, info, msg)
+    }
+
+    /// Replaces the accepted payment denoms. Owner only.
+    pub fn set_payment_denoms(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        denoms: Vec<String>,
+    ) -> Result<Response<Binary>, ContractError> {
+        self.ensure_owner(deps.as_ref(), env, &info.sender)?;
+        validate_payment_denoms(deps.api, &denoms)?;
+        PAYMENT_DENOMS.save(deps.storage, &denoms)?;
+        Ok(Response::new()
+            .add_attribute("action", "set_payment_denoms")
+            .add_attribute("denoms", denoms.join(",")))
+    }
+
+    /// Errors unless `sender` is the owner recorded by the ownable module
+    pub fn ensure_owner(&self, deps: Deps, env: &Env, sender: &Addr) -> Result<(), ContractError> {
+        if !self.is_owner(deps, env, sender)? {
+            return Err(ContractError::Unauthorized {});
+        }
+        Ok(())
+    }
+
+    /// Whether `address` is the owner recorded by the ownable module
+    pub fn is_owner(&self, deps: Deps, env: &Env, address: &Addr) -> Result<bool, ContractError> {
+        let resp = self
+            .ownable
+            .borrow()
+            .query(
+                &deps,
+                env.clone(),
+                ownable::QueryMsg::IsOwner(address.to_string()),
+            )
+            .map_err(ContractError::OwnableError)?;
+        match resp {
+            ownable::QueryResp::IsOwner(is_owner) => Ok(is_owner),
+        }
+    }
+
+    /// Buys a listed seat, paying the platform fee and the royalty of the seat
+    /// out of its price
+    pub fn buy_token(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+    ) -> Result<Response<Binary>, ContractError> {
+        let price = self.ensure_listed(deps.as_ref(), env, &token_id)?;
+        self.ensure_transferable(deps.as_ref(), &token_id)?;
+        let excess = check_payment(&info.funds, &price)?;
+        let response = self.settle_sale(deps, "buy_token", &token_id, &info.sender, &price)?;
+        Ok(refund_excess(response, &info.sender, &price.denom, excess))
+    }
+
+    /// Returns the price of the unexpired listing of `token_id`
+    fn ensure_listed(&self, deps: Deps, env: &Env, token_id: &str) -> Result<Coin, ContractError> {
+        let price = self
+            .sellable_token
+            .borrow()
+            .listed_tokens
+            .may_load(deps.storage, token_id)?
+            .ok_or_else(|| ContractError::TokenNotListed {
+                token_id: token_id.to_string(),
+            })?;
+        if listing_expired(deps.storage, token_id, &env.block.time)? {
+            return Err(ContractError::ListingExpired {
+                token_id: token_id.to_string(),
+            });
+        }
+        Ok(price)
+    }
+
+    /// Lists transferable seats through the sellable module, recording the
+    /// expiry of each listing
+    pub fn list(
+        &mut self,
+        deps: &mut Box<DepsMut>,
+        env: Env,
+        info: MessageInfo,
+        listings: Vec<ListingMsg>,
+    ) -> Result<Response<Binary>, ContractError> {
+        let mut prices = schemars::Map::new();
+        let payment_denoms = PAYMENT_DENOMS.load(deps.storage)?;
+        for listing in listings {
+            self.ensure_transferable(deps.as_ref(), &listing.token_id)?;
+            if !payment_denoms.contains(&listing.price.denom) {
+                return Err(ContractError::UnsupportedPaymentAsset {
+                    denom: listing.price.denom,
+                });
+            }
+            match listing.expires_at {
+                Some(expires_at) if expires_at <= env.block.time => {
+                    return Err(ContractError::InvalidListing {
+                        reason: "listings must expire in the future".to_string(),
+                    })
+                }
+                Some(expires_at) => {
+                    LISTING_EXPIRIES.save(deps.storage, &listing.token_id, &expires_at)?
+                }
+                None => LISTING_EXPIRIES.remove(deps.storage, &listing.token_id),
+            }
+            prices.insert(listing.token_id, listing.price);
+        }
+        let res = self
+            .sellable_token
+            .borrow_mut()
+            .execute(
+                deps,
+                env,
+                info,
+                sellable::msg::ExecuteMsg::List { listings: prices },
+            )
+            .map_err(ContractError::SellableError)?;
+        Ok(res.response)
+    }
+
+    /// Delists every seat the sender owns
+    pub fn delist_all(
+        &self,
+        deps: DepsMut,
+        info: &MessageInfo,
+    ) -> Result<Response<Binary>, ContractError> {
+        let token_ids = self
+            .seat_token
+            .borrow()
+            .contract
+            .tokens
+            .idx
+            .owner
+            .prefix(info.sender.clone())
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        let mut delisted = 0u32;
+        for token_id in token_ids {
+            if self
+                .sellable_token
+                .borrow()
+                .listed_tokens
+                .has(deps.storage, &token_id)
+            {
+                self.delist(deps.storage, &token_id);
+                delisted += 1;
             }
+        }
+        Ok(Response::new()
+            .add_attribute("action", "delist_all")
+            .add_attribute("owner", info.sender.clone())
+            .add_attribute("delisted", delisted.to_string()))
+    }
+
+    fn delist(&self, storage: &mut dyn Storage, token_id: &str) {
+        self.sellable_token
+            .borrow()
+            .listed_tokens
+            .remove(storage, token_id);
+        LISTING_EXPIRIES.remove(storage, token_id);
+    }
+
+    /// Transfers the seat `token_id` sold for `price` to `buyer`. The price,
+    /// held by the contract, is split between the seller, the seat's royalty
+    /// address and the platform fee collector.
+    fn settle_sale(
+        &self,
+        deps: DepsMut,
+        action: &str,
+        token_id: &str,
+        buyer: &Addr,
+        price: &Coin,
+    ) -> Result<Response<Binary>, ContractError> {
+        let seat_token = self.seat_token.borrow();
+        let tokens = &seat_token.contract.tokens;
+        let mut token = self.load_seat(deps.storage, token_id)?;
+        let seller = token.owner;
+        token.owner = buyer.clone();
+        token.approvals = vec![];
+        tokens.save(deps.storage, token_id, &token)?;
+        self.delist(deps.storage, token_id);
+
+        let split = split_sale(deps.storage, &token.extension, price)?;
+        Ok(Response::new()
+            .add_attribute("action", action)
+            .add_attribute("token_id", token_id)
+            .add_attribute("seller", seller.clone())
+            .add_attribute("buyer", buyer.clone())
+            .add_attribute("price", price.to_string())
+            .add_attributes(split.attributes())
+            .add_messages(split.into_msgs(&seller)))
+    }
+
+    /// Auctions the seat `token_id`, which stays with the sender until the
+    /// auction is settled
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_auction(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+        denom: String,
+        kind: AuctionKind,
+        start: Option<Timestamp>,
+        end: Timestamp,
+    ) -> Result<Response<Binary>, ContractError> {
+        self.ensure_seat_owner(deps.as_ref(), &token_id, &info.sender)?;
+        self.ensure_transferable(deps.as_ref(), &token_id)?;
+        let start = start.unwrap_or(env.block.time).max(env.block.time);
+        let auction = Auction::new(deps.storage, info.sender.clone(), denom, kind, start, end)?;
+        AUCTIONS.save(deps.storage, &token_id, &auction)?;
+        // the auction replaces any fixed price listing
+        self.delist(deps.storage, &token_id);
+        Ok(Response::new()
+            .add_attribute("action", "start_auction")
+            .add_attribute("token_id", token_id)
+            .add_attribute("start", start.seconds().to_string())
+            .add_attribute("end", end.seconds().to_string()))
+    }
+
+    /// Escrows a bid on the English auction of `token_id`, refunding the bid
+    /// it outbids
+    pub fn bid_auction(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+    ) -> Result<Response<Binary>, ContractError> {
+        let mut auction = load_open_auction(deps.storage, env, &token_id)?;
+        if !matches!(auction.kind, AuctionKind::English { .. }) {
+            return Err(ContractError::InvalidAuction {
+                reason: "only English auctions take bids".to_string(),
+            });
+        }
+        if info.sender == auction.seller {
+            return Err(ContractError::Unauthorized {});
+        }
+        let min_bid =
+            auction
+                .asking_price(env.block.time)
+                .ok_or_else(|| ContractError::AuctionNotOpen {
+                    token_id: token_id.clone(),
+                })?;
+        // the whole payment is bid
+        let amount = match check_payment(&info.funds, &min_bid) {
+            Ok(excess) => min_bid.amount + excess,
+            Err(ContractError::InsufficientFunds { .. }) => {
+                return Err(ContractError::BidTooLow { min_bid })
+            }
+            Err(err) => return Err(err),
+        };
+
+        let mut response = Response::new();
+        if let Some(outbid) = auction.highest_bid.take() {
+            response = response.add_message(BankMsg::Send {
+                to_address: outbid.bidder.to_string(),
+                amount: vec![Coin {
+                    denom: auction.denom.clone(),
+                    amount: outbid.amount,
+                }],
+            });
+        }
+        auction.highest_bid = Some(Bid {
+            bidder: info.sender.clone(),
+            amount,
+        });
+        AUCTIONS.save(deps.storage, &token_id, &auction)?;
+        Ok(response
+            .add_attribute("action", "bid_auction")
+            .add_attribute("token_id", token_id)
+            .add_attribute("bidder", info.sender.clone())
+            .add_attribute("amount", amount))
+    }
+
+    /// Sells the seat of the Dutch auction of `token_id` at its current price
+    pub fn buy_auction(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+    ) -> Result<Response<Binary>, ContractError> {
+        let auction = load_open_auction(deps.storage, env, &token_id)?;
+        if !matches!(auction.kind, AuctionKind::Dutch { .. }) {
+            return Err(ContractError::InvalidAuction {
+                reason: "only Dutch auctions can be bought outright".to_string(),
+            });
+        }
+        if !self.is_transferable(deps.as_ref(), &token_id)? {
+            return Err(ContractError::NonTransferable { token_id });
+        }
+        let price =
+            auction
+                .asking_price(env.block.time)
+                .ok_or_else(|| ContractError::AuctionNotOpen {
+                    token_id: token_id.clone(),
+                })?;
+        let excess = check_payment(&info.funds, &price)?;
+        AUCTIONS.remove(deps.storage, &token_id);
 
-            QueryMsg::Metadata(msg) => to_binary(&self.metadata.query(&deps, env, msg).unwrap()),
+        let response = self.settle_sale(deps, "buy_auction", &token_id, &info.sender, &price)?;
+        Ok(refund_excess(response, &info.sender, &price.denom, excess))
+    }
+
+    /// Ends the auction of `token_id` once its end passed. The highest bid of
+    /// an English auction wins the seat, otherwise the seat stays with the
+    /// seller.
+    pub fn settle_auction(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        token_id: String,
+    ) -> Result<Response<Binary>, ContractError> {
+        let auction = load_auction(deps.storage, &token_id)?;
+        if env.block.time < auction.end {
+            return Err(ContractError::AuctionNotEnded { token_id });
+        }
+        AUCTIONS.remove(deps.storage, &token_id);
+        if !self.is_transferable(deps.as_ref(), &token_id)? {
+            // a seat that became soulbound stays with its seller
+            return Ok(Response::new()
+                .add_attribute("action", "settle_auction")
+                .add_attribute("token_id", token_id)
+                .add_messages(refund_highest_bid(auction)));
+        }
+        match auction.highest_bid {
+            Some(bid) => {
+                let price = Coin {
+                    denom: auction.denom,
+                    amount: bid.amount,
+                };
+                self.settle_sale(deps, "settle_auction", &token_id, &bid.bidder, &price)
+            }
+            None => Ok(Response::new()
+                .add_attribute("action", "settle_auction")
+                .add_attribute("token_id", token_id)),
+        }
+    }
+
+    /// Cancels the auction of `token_id`, only possible for its seller until
+    /// someone bids
+    pub fn cancel_auction(
+        &self,
+        deps: DepsMut,
+        info: &MessageInfo,
+        token_id: String,
+    ) -> Result<Response<Binary>, ContractError> {
+        let auction = load_auction(deps.storage, &token_id)?;
+        if info.sender != auction.seller {
+            return Err(ContractError::Unauthorized {});
+        }
+        if auction.highest_bid.is_some() {
+            return Err(ContractError::InvalidAuction {
+                reason: "auctions with bids cannot be cancelled".to_string(),
+            });
+        }
+        AUCTIONS.remove(deps.storage, &token_id);
+        Ok(Response::new()
+            .add_attribute("action", "cancel_auction")
+            .add_attribute("token_id", token_id))
+    }
+
+    /// Escrows the funds sent as an offer for the seat `token_id`, or for any
+    /// seat if `None`
+    pub fn make_offer(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: Option<String>,
+        expires_at: Timestamp,
+    ) -> Result<Response<Binary>, ContractError> {
+        if let Some(token_id) = &token_id {
+            let owner = self.load_seat(deps.storage, token_id)?.owner;
+            if owner == info.sender {
+                return Err(ContractError::InvalidOffer {
+                    reason: "owners cannot make offers for their own seat".to_string(),
+                });
+            }
+            if !self.is_transferable(deps.as_ref(), token_id)? {
+                return Err(ContractError::NonTransferable {
+                    token_id: token_id.clone(),
+                });
+            }
+        }
+        let offer = make_offer(
+            deps.storage,
+            &env.block.time,
+            info.sender.clone(),
+            &info.funds,
+            token_id,
+            expires_at,
+        )?;
+        Ok(Response::new()
+            .add_attribute("action", "make_offer")
+            .add_attribute("offer_id", offer.id.to_string())
+            .add_attribute("token_id", offer.token_id.unwrap_or_default())
+            .add_attribute("buyer", offer.buyer)
+            .add_attribute("price", offer.price.to_string()))
+    }
+
+    /// Sells the seat `token_id` to the buyer of an unexpired offer for it
+    pub fn accept_offer(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        offer_id: u64,
+        token_id: String,
+    ) -> Result<Response<Binary>, ContractError> {
+        let offer = load_offer(deps.storage, offer_id)?;
+        if offer.is_expired(&env.block.time) {
+            return Err(ContractError::OfferExpired { offer_id });
+        }
+        if !offer.is_for(&token_id) {
+            return Err(ContractError::InvalidOffer {
+                reason: "the offer is for another seat".to_string(),
+            });
+        }
+        self.ensure_seat_owner(deps.as_ref(), &token_id, &info.sender)?;
+        self.ensure_transferable(deps.as_ref(), &token_id)?;
+        OFFERS.remove(deps.storage, offer_id);
+        Ok(self
+            .settle_sale(deps, "accept_offer", &token_id, &offer.buyer, &offer.price)?
+            .add_attribute("offer_id", offer_id.to_string()))
+    }
+
+    /// Refunds an offer for a seat the sender owns
+    pub fn reject_offer(
+        &self,
+        deps: DepsMut,
+        info: &MessageInfo,
+        offer_id: u64,
+    ) -> Result<Response<Binary>, ContractError> {
+        let offer = load_offer(deps.storage, offer_id)?;
+        let token_id = offer
+            .token_id
+            .as_deref()
+            .ok_or_else(|| ContractError::InvalidOffer {
+                reason: "collection offers cannot be rejected".to_string(),
+            })?;
+        self.ensure_seat_owner(deps.as_ref(), token_id, &info.sender)?;
+        OFFERS.remove(deps.storage, offer_id);
+        Ok(refund_offer("reject_offer", offer))
+    }
+
+    /// Refunds an offer, on behalf of its buyer or once it expired
+    pub fn cancel_offer(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        offer_id: u64,
+    ) -> Result<Response<Binary>, ContractError> {
+        let offer = load_offer(deps.storage, offer_id)?;
+        if info.sender != offer.buyer && !offer.is_expired(&env.block.time) {
+            return Err(ContractError::Unauthorized {});
+        }
+        OFFERS.remove(deps.storage, offer_id);
+        Ok(refund_offer("cancel_offer", offer))
+    }
+
+    /// Errors if the seat `token_id` is soulbound or auctioned. A tier setting
+    /// overrides the collection one.
+    pub fn ensure_transferable(&self, deps: Deps, token_id: &str) -> Result<(), ContractError> {
+        ensure_not_auctioned(deps.storage, token_id)?;
+        if !self.is_transferable(deps, token_id)? {
+            return Err(ContractError::NonTransferable {
+                token_id: token_id.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Delists the seats that are no longer transferable and cancels their
+    /// auctions, refunding the highest bids
+    fn withdraw_soulbound_seats(
+        &self,
+        deps: DepsMut,
+        mut response: Response<Binary>,
+    ) -> Result<Response<Binary>, ContractError> {
+        let listed = self
+            .sellable_token
+            .borrow()
+            .listed_tokens
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        let mut delisted = 0u32;
+        for token_id in listed {
+            if !self.is_transferable(deps.as_ref(), &token_id)? {
+                self.delist(deps.storage, &token_id);
+                delisted += 1;
+            }
+        }
+        let auctions = AUCTIONS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        let mut cancelled = 0u32;
+        for (token_id, auction) in auctions {
+            if !self.is_transferable(deps.as_ref(), &token_id)? {
+                AUCTIONS.remove(deps.storage, &token_id);
+                response = response.add_messages(refund_highest_bid(auction));
+                cancelled += 1;
+            }
+        }
+        Ok(response
+            .add_attribute("delisted", delisted.to_string())
+            .add_attribute("cancelled_auctions", cancelled.to_string()))
+    }
+
+    pub fn is_transferable(&self, deps: Deps, token_id: &str) -> StdResult<bool> {
+        let token = self
+            .seat_token
+            .borrow()
+            .contract
+            .tokens
+            .load(deps.storage, token_id)?;
+        let tier_transferable = match &token.extension.tier {
+            Some(tier_id) => tiers::TIERS
+                .may_load(deps.storage, tier_id)?
+                .and_then(|tier| tier.transferable),
+            None => None,
+        };
+        match tier_transferable {
+            Some(transferable) => Ok(transferable),
+            None => Ok(TRANSFERABLE.may_load(deps.storage)?.unwrap_or(true)),
+        }
+    }
+
+    /// Burns the seat `token_id`, whatever its owner. Only the owner of the
+    /// hub the collection belongs to can revoke seats.
+    pub fn revoke_seat(
+        &self,
+        deps: DepsMut,
+        info: &MessageInfo,
+        token_id: String,
+    ) -> Result<Response<Binary>, ContractError> {
+        let hub_contract = HUB_CONTRACT.load(deps.storage)?;
+        let is_hub_owner: ownable::QueryResp = deps.querier.query_wasm_smart(
+            &hub_contract,
+            &HubQueryMsg::Ownable(ownable::QueryMsg::IsOwner(info.sender.to_string())),
+        )?;
+        match is_hub_owner {
+            ownable::QueryResp::IsOwner(true) => {}
+            ownable::QueryResp::IsOwner(false) => return Err(ContractError::Unauthorized {}),
+        }
+
+        let seat_token = self.seat_token.borrow();
+        let token = self.load_seat(deps.storage, &token_id)?;
+        seat_token.contract.tokens.remove(deps.storage, &token_id)?;
+        seat_token.contract.decrement_tokens(deps.storage)?;
+        remove_subscription(deps.storage, &token_id)?;
+        self.delist(deps.storage, &token_id);
+
+        let mut response = Response::new();
+        if let Some(auction) = AUCTIONS.may_load(deps.storage, &token_id)? {
+            AUCTIONS.remove(deps.storage, &token_id);
+            response = response.add_messages(refund_highest_bid(auction));
+        }
+        Ok(response
+            .add_attribute("action", "revoke_seat")
+            .add_attribute("token_id", token_id)
+            .add_attribute("owner", token.owner))
+    }
+
+    /// Extends the expiry of the seat `token_id` by the renewal duration. The
+    /// renewal price is paid to the renewal recipient, less the platform fee.
+    pub fn renew_seat(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+    ) -> Result<Response<Binary>, ContractError> {
+        let not_renewable = || ContractError::NotRenewable {
+            token_id: token_id.clone(),
+        };
+        // subscriptions are extended with `Subscribe`
+        if SUBSCRIPTIONS.has(deps.storage, &token_id) {
+            return Err(not_renewable());
+        }
+        let config = RENEWAL_CONFIG
+            .may_load(deps.storage)?
+            .ok_or_else(not_renewable)?;
+        let excess = check_payment(&info.funds, &config.price)?;
+
+        let seat_token = self.seat_token.borrow();
+        let tokens = &seat_token.contract.tokens;
+        let mut token = self.load_seat(deps.storage, &token_id)?;
+        let expires_at = token.extension.expires_at.ok_or_else(not_renewable)?;
+        let expires_at = config.renewed_expiry(expires_at, env.block.time);
+        token.extension.expires_at = Some(expires_at);
+        tokens.save(deps.storage, &token_id, &token)?;
+
+        let denom = config.price.denom.clone();
+        let response = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: config.recipient.to_string(),
+                amount: vec![config.price],
+            })
+            .add_attribute("action", "renew_seat")
+            .add_attribute("token_id", token_id)
+            .add_attribute("expires_at", expires_at.seconds().to_string());
+        // the excess is refunded once the fee was taken out of the payouts
+        let response = take_primary_sale_fee(deps.storage, response)?;
+        Ok(refund_excess(response, &info.sender, &denom, excess))
+    }
+
+    /// Primary sale of the seat described by `mint_msg` through the sales
+    /// module, once the sender is checked against the active sale phase
+    pub fn buy_item(
+        &mut self,
+        deps: &mut Box<DepsMut>,
+        env: Env,
+        info: MessageInfo,
+        mut mint_msg: MintMsg<TokenMetadata>,
+        proof: &[String],
+    ) -> Result<Response<Binary>, ContractError> {
+        phases::record_phase_mint(deps.storage, env.block.time, &info.sender, proof)?;
+        // the collection decides the tier of sold seats, buyers cannot pick
+        // a scarcer one for the same price
+        let sale_tier = tiers::SALE_TIER.may_load(deps.storage)?;
+        if mint_msg.extension.tier.is_some() && mint_msg.extension.tier != sale_tier {
+            return Err(ContractError::InvalidTier {
+                reason: "the tier of sold seats is set by the collection".to_string(),
+            });
+        }
+        mint_msg.extension.tier = sale_tier;
+        if mint_msg.extension.expires_at.is_some() {
+            return Err(ContractError::InvalidRenewal {
+                reason: "the expiry of sold seats is set by the collection".to_string(),
+            });
+        }
+        mint_msg.extension.expires_at = primary_sale_expiry(deps.storage, env.block.time)?;
+        if mint_msg.extension.royalty_percentage.is_some()
+            || mint_msg.extension.royalty_payment_address.is_some()
+        {
+            return Err(ContractError::InvalidRoyalty {
+                reason: "the royalty of sold seats is set by the collection".to_string(),
+            });
+        }
+        if let Some(config) = ROYALTY_CONFIG.may_load(deps.storage)? {
+            mint_msg.extension.royalty_percentage = Some(config.percentage);
+            mint_msg.extension.royalty_payment_address = Some(config.payment_address.to_string());
+        }
+        record_tier_mint(deps.storage, &mint_msg.extension)?;
+        // the sales module is paid the exact price, the excess is refunded
+        let mut info = info;
+        let price = self.primary_sale_price(deps.as_ref(), &env, &info.funds)?;
+        let excess = check_payment(&info.funds, &price)?;
+        info.funds = vec![price.clone()];
+        let payer = info.sender.clone();
+        let res = self
+            .sales
+            .execute(deps, env, info, sales::msg::ExecuteMsg::BuyItem(mint_msg))
+            .map_err(ContractError::SalesError)?;
+        let response = take_primary_sale_fee(deps.storage, res.response)?;
+        Ok(refund_excess(response, &payer, &price.denom, excess))
+    }
+
+    /// Price, in the denom of `funds` if the sale accepts it, of the running
+    /// primary sale
+    fn primary_sale_price(
+        &self,
+        deps: Deps,
+        env: &Env,
+        funds: &[Coin],
+    ) -> Result<Coin, ContractError> {
+        let sale = match self
+            .sales
+            .query(
+                &deps,
+                env.clone(),
+                sales::msg::QueryMsg::ActivePrimarySale {},
+            )
+            .map_err(ContractError::SalesError)?
+        {
+            sales::msg::QueryResp::ActivePrimarySale(Some(sale)) if !sale.disabled => sale,
+            _ => return Err(ContractError::SaleNotActive {}),
+        };
+        let paid_denom = funds.first().map(|coin| coin.denom.as_str());
+        let price = sale
+            .price
+            .iter()
+            .find(|price| Some(price.denom.as_str()) == paid_denom)
+            .or_else(|| sale.price.first())
+            .cloned()
+            .ok_or(ContractError::SaleNotActive {})?;
+        // the payment denoms may have changed since the sale was created
+        if !PAYMENT_DENOMS.load(deps.storage)?.contains(&price.denom) {
+            return Err(ContractError::UnsupportedPaymentAsset { denom: price.denom });
+        }
+        Ok(price)
+    }
+
+    /// Pays `periods` billing periods of the subscription `token_id` to the
+    /// subscription recipient, less the platform fee. The seat is minted to
+    /// the sender on the first payment, anyone can pay for later periods.
+    pub fn subscribe(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        token_id: String,
+        periods: u32,
+    ) -> Result<Response<Binary>, ContractError> {
+        let config = SUBSCRIPTION_CONFIG.may_load(deps.storage)?.ok_or_else(|| {
+            ContractError::InvalidSubscription {
+                reason: "subscriptions are not enabled".to_string(),
+            }
+        })?;
+        let price = config.price_of(periods)?;
+        let excess = check_payment(&info.funds, &price)?;
+
+        let seat_token = self.seat_token.borrow();
+        let contract = &seat_token.contract;
+        let subscription = SUBSCRIPTIONS.may_load(deps.storage, &token_id)?;
+        let paid_until = config.paid_until(subscription, env.block.time, periods);
+        let expires_at = Some(config.expires_at(paid_until));
+        match (
+            subscription,
+            contract.tokens.may_load(deps.storage, &token_id)?,
+        ) {
+            (Some(_), Some(mut token)) => {
+                token.extension.expires_at = expires_at;
+                contract.tokens.save(deps.storage, &token_id, &token)?;
+            }
+            (None, None) => {
+                let extension = TokenMetadata {
+                    name: None,
+                    description: None,
+                    royalty_percentage: None,
+                    royalty_payment_address: None,
+                    tier: config.tier,
+                    expires_at,
+                };
+                record_tier_mint(deps.storage, &extension)?;
+                let token = TokenInfo {
+                    owner: info.sender.clone(),
+                    approvals: vec![],
+                    token_uri: None,
+                    extension,
+                };
+                contract.tokens.save(deps.storage, &token_id, &token)?;
+                contract.increment_tokens(deps.storage)?;
+            }
+            _ => {
+                return Err(ContractError::InvalidSubscription {
+                    reason: format!("seat {} is not a subscription", token_id),
+                })
+            }
+        }
+        save_subscription(deps.storage, &token_id, paid_until)?;
+
+        let denom = price.denom.clone();
+        let response = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: config.recipient.to_string(),
+                amount: vec![price],
+            })
+            .add_attribute("action", "subscribe")
+            .add_attribute("token_id", token_id)
+            .add_attribute("periods", periods.to_string())
+            .add_attribute("paid_until", paid_until.seconds().to_string());
+        let response = take_primary_sale_fee(deps.storage, response)?;
+        Ok(refund_excess(response, &info.sender, &denom, excess))
+    }
+
+    /// Errors if the seat `token_id` has expired
+    pub fn ensure_active(
+        &self,
+        deps: Deps,
+        env: &Env,
+        token_id: &str,
+    ) -> Result<(), ContractError> {
+        let token = self.load_seat(deps.storage, token_id)?;
+        if !is_active(&token.extension, &env.block.time) {
+            return Err(ContractError::SeatExpired {
+                token_id: token_id.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Errors unless `sender` currently owns the seat token `token_id`
+    pub fn ensure_seat_owner(
+        &self,
+        deps: Deps,
+        token_id: &str,
+        sender: &Addr,
+    ) -> Result<(), ContractError> {
+        let token = self.load_seat(deps.storage, token_id)?;
+        if token.owner != *sender {
+            return Err(ContractError::NotSeatOwner {
+                token_id: token_id.to_string(),
+                sender: sender.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn load_seat(
+        &self,
+        storage: &dyn Storage,
+        token_id: &str,
+    ) -> Result<TokenInfo<TokenMetadata>, ContractError> {
+        self.seat_token
+            .borrow()
+            .contract
+            .tokens
+            .may_load(storage, token_id)?
+            .ok_or_else(|| ContractError::SeatNotFound {
+                token_id: token_id.to_string(),
+            })
+    }
+
+    pub fn query(&self, deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::Ownable(msg) => to_binary(
+                &self
+                    .ownable
+                    .borrow()
+                    .query(&deps, env, msg)
+                    .map_err(|err| module_query_error("ownable", err))?,
+            ),
+            QueryMsg::Metadata(msg) => to_binary(
+                &self
+                    .metadata
+                    .query(&deps, env, msg)
+                    .map_err(|err| module_query_error("metadata", err))?,
+            ),
             QueryMsg::SeatToken(msg) => {
-                let res = self.seat_token.borrow_mut().query(&deps, env, msg).unwrap();
+                let res = self
+                    .seat_token
+                    .borrow_mut()
+                    .query(&deps, env, msg)
+                    .map_err(|err| module_query_error("seat_token", err))?;
                 match res {
                     token::QueryResp::Result(resp) => Ok(resp),
                 }
             }
-            QueryMsg::Redeemable(msg) => {
-                to_binary(&self.redeemable.query(&deps, env, msg).unwrap())
-            }
+            QueryMsg::Redeemable(msg) => to_binary(
+                &self
+                    .redeemable
+                    .query(&deps, env, msg)
+                    .map_err(|err| module_query_error("redeemable", err))?,
+            ),
             QueryMsg::Sellable(msg) => to_binary(
                 &self
                     .sellable_token
                     .borrow_mut()
                     .query(&deps, env, msg)
-                    .unwrap(),
+                    .map_err(|err| module_query_error("sellable", err))?,
             ),
-            QueryMsg::Sales(msg) => to_binary(&self.sales.query(&deps, env, msg).unwrap()),
-            QueryMsg::AllSeats {} => to_binary(&self.get_all_seats(deps)),
+            QueryMsg::Sales(msg) => to_binary(
+                &self
+                    .sales
+                    .query(&deps, env, msg)
+                    .map_err(|err| module_query_error("sales", err))?,
+            ),
+            QueryMsg::AllSeats {
+                start_after,
+                limit,
+                owner,
+                listed_only,
+                min_price,
+                max_price,
+            } => to_binary(&self.get_all_seats(
+                deps,
+                &env,
+                start_after,
+                limit,
+                SeatFilters {
+                    owner,
+                    listed_only,
+                    min_price,
+                    max_price,
+                },
+            )?),
+            QueryMsg::HubContract {} => to_binary(&HUB_CONTRACT.load(deps.storage)?),
+            QueryMsg::PaymentDenoms {} => to_binary(&PAYMENT_DENOMS.load(deps.storage)?),
+            QueryMsg::PaymentAssets {} => {
+                let assets: Vec<PaymentAsset> = PAYMENT_DENOMS
+                    .load(deps.storage)?
+                    .iter()
+                    .map(|denom| PaymentAsset::from_denom(denom))
+                    .collect();
+                to_binary(&assets)
+            }
+            QueryMsg::DryRunMigration { from_version } => {
+                to_binary(&dry_run_migration(deps, from_version)?)
+            }
+            QueryMsg::SalePhases {} => {
+                to_binary(&SALE_PHASES.may_load(deps.storage)?.unwrap_or_default())
+            }
+            QueryMsg::ActiveSalePhase {} => {
+                match phases::active_sale_phase(deps.storage, env.block.time) {
+                    Ok(phase) => to_binary(&phase),
+                    Err(ContractError::SaleNotActive {}) => to_binary(&None::<SalePhase>),
+                    Err(err) => Err(StdError::generic_err(err.to_string())),
+                }
+            }
+            QueryMsg::Eligibility { address, proof } => {
+                let address = deps.api.addr_validate(&address)?;
+                to_binary(
+                    &phases::eligibility(
+                        deps.storage,
+                        env.block.time,
+                        &address,
+                        &proof.unwrap_or_default(),
+                    )
+                    .map_err(|err| StdError::generic_err(err.to_string()))?,
+                )
+            }
+            QueryMsg::Auction { token_id } => {
+                let auction = load_auction(deps.storage, &token_id)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?;
+                to_binary(&AuctionInfo {
+                    token_id,
+                    asking_price: auction.asking_price(env.block.time),
+                    auction,
+                })
+            }
+            QueryMsg::Offer { offer_id } => {
+                let offer = load_offer(deps.storage, offer_id)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?;
+                to_binary(&offer)
+            }
+            QueryMsg::Offers {
+                token_id,
+                buyer,
+                start_after,
+                limit,
+            } => {
+                let buyer = buyer
+                    .map(|buyer| deps.api.addr_validate(&buyer))
+                    .transpose()?;
+                let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+                to_binary(&list_offers(
+                    deps.storage,
+                    token_id.as_deref(),
+                    buyer.as_ref(),
+                    start_after,
+                    limit,
+                )?)
+            }
+            QueryMsg::Auctions { start_after, limit } => {
+                let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+                let auctions = AUCTIONS
+                    .range(
+                        deps.storage,
+                        exclusive_start(&start_after),
+                        None,
+                        Order::Ascending,
+                    )
+                    .take(limit)
+                    .map(|item| {
+                        item.map(|(token_id, auction)| AuctionInfo {
+                            token_id,
+                            asking_price: auction.asking_price(env.block.time),
+                            auction,
+                        })
+                    })
+                    .collect::<StdResult<_>>()?;
+                to_binary(&AuctionsResponse { auctions })
+            }
+            QueryMsg::Tier { tier_id } => to_binary(
+                &tiers::load_tier(deps.storage, &tier_id)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?,
+            ),
+            QueryMsg::Tiers { start_after, limit } => {
+                to_binary(&tiers::query_tiers(deps.storage, start_after, limit)?)
+            }
+            QueryMsg::SaleTier {} => to_binary(&tiers::SALE_TIER.may_load(deps.storage)?),
+            QueryMsg::IsTransferable { token_id } => {
+                to_binary(&self.is_transferable(deps, &token_id)?)
+            }
+            QueryMsg::IsActive { token_id } => {
+                let token = self
+                    .seat_token
+                    .borrow()
+                    .contract
+                    .tokens
+                    .load(deps.storage, &token_id)?;
+                to_binary(&is_active(&token.extension, &env.block.time))
+            }
+            QueryMsg::RenewalConfig {} => to_binary(&RENEWAL_CONFIG.may_load(deps.storage)?),
+            QueryMsg::SubscriptionConfig {} => {
+                to_binary(&SUBSCRIPTION_CONFIG.may_load(deps.storage)?)
+            }
+            QueryMsg::Subscription { token_id } => {
+                let paid_until = SUBSCRIPTIONS.load(deps.storage, &token_id)?;
+                to_binary(&self.subscription_info(deps, token_id, paid_until)?)
+            }
+            QueryMsg::ExpiringSubscriptions {
+                from,
+                to,
+                start_after,
+                limit,
+            } => {
+                let subscriptions =
+                    expiring_subscriptions(deps.storage, from, to, start_after, limit)?
+                        .into_iter()
+                        .map(|(token_id, paid_until)| {
+                            self.subscription_info(deps, token_id, paid_until)
+                        })
+                        .collect::<StdResult<_>>()?;
+                to_binary(&SubscriptionsResponse { subscriptions })
+            }
+            QueryMsg::FeeConfig {} => to_binary(&FEE_CONFIG.may_load(deps.storage)?),
+            QueryMsg::RoyaltyConfig {} => to_binary(&ROYALTY_CONFIG.may_load(deps.storage)?),
+            QueryMsg::RoyaltyInfo {
+                token_id,
+                sale_price,
+            } => {
+                let token = self
+                    .seat_token
+                    .borrow()
+                    .contract
+                    .tokens
+                    .load(deps.storage, &token_id)?;
+                to_binary(&royalty_info(&token.extension, sale_price))
+            }
         }
     }
 
-    pub fn get_all_seats(&self, deps: Deps) -> Vec<SeatInfo> {
-        let seat_token = &self.seat_token.borrow().contract;
-        let listed = &self.sellable_token.borrow().listed_tokens;
-        seat_token
+    fn subscription_info(
+        &self,
+        deps: Deps,
+        token_id: String,
+        paid_until: Timestamp,
+    ) -> StdResult<SubscriptionInfo> {
+        let token = self
+            .seat_token
+            .borrow()
+            .contract
             .tokens
-            .range(deps.storage, None, None, Order::Ascending)
-            .flatten()
-            .map(|(token_id, info)| {
-                let listed_price = listed.load(deps.storage, token_id.as_str()).ok();
-                SeatInfo {
-                    token_id,
-                    listed_price,
-                    owner: info.owner,
-                    approvals: info.approvals,
-                    token_uri: info.token_uri,
-                    extension: info.extension,
+            .load(deps.storage, &token_id)?;
+        Ok(SubscriptionInfo {
+            token_id,
+            owner: token.owner,
+            paid_until,
+            expires_at: token.extension.expires_at.unwrap_or(paid_until),
+        })
+    }
+
+    pub fn get_all_seats(
+        &self,
+        deps: Deps,
+        env: &Env,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        filters: SeatFilters,
+    ) -> StdResult<AllSeatsResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let owner = filters
+            .owner
+            .map(|owner| deps.api.addr_validate(&owner))
+            .transpose()?;
+        let listed_only = filters.listed_only.unwrap_or(false)
+            || filters.min_price.is_some()
+            || filters.max_price.is_some();
+
+        let seat_token_module = self.seat_token.borrow();
+        let seat_token = &seat_token_module.contract;
+        let sellable_token = self.sellable_token.borrow();
+        let listed = &sellable_token.listed_tokens;
+        // scan the narrowest index that satisfies the filters
+        let token_ids: Box<dyn Iterator<Item = StdResult<String>> + '_> = if listed_only {
+            Box::new(merge_token_ids(
+                listed.keys(
+                    deps.storage,
+                    exclusive_start(&start_after),
+                    None,
+                    Order::Ascending,
+                ),
+                AUCTIONS.keys(
+                    deps.storage,
+                    exclusive_start(&start_after),
+                    None,
+                    Order::Ascending,
+                ),
+            ))
+        } else if let Some(owner) = &owner {
+            seat_token.tokens.idx.owner.prefix(owner.clone()).keys(
+                deps.storage,
+                exclusive_start(&start_after),
+                None,
+                Order::Ascending,
+            )
+        } else {
+            seat_token.tokens.keys(
+                deps.storage,
+                exclusive_start(&start_after),
+                None,
+                Order::Ascending,
+            )
+        };
+
+        let mut seats = vec![];
+        let mut last_scanned = None;
+        let mut exhausted = true;
+        for (scanned, token_id) in token_ids.enumerate() {
+            if seats.len() == limit || scanned == MAX_SCANNED_SEATS {
+                exhausted = false;
+                break;
+            }
+            let token_id = token_id?;
+            last_scanned = Some(token_id.clone());
+            let info = seat_token.tokens.load(deps.storage, &token_id)?;
+            if let Some(owner) = &owner {
+                if info.owner != *owner {
+                    continue;
                 }
-            })
-            .collect()
+            }
+            // open auctions are listed at their asking price
+            let auction = AUCTIONS.may_load(deps.storage, &token_id)?;
+            let listing_expires_at = LISTING_EXPIRIES.may_load(deps.storage, &token_id)?;
+            let listed_price = match &auction {
+                Some(auction) => auction.asking_price(env.block.time),
+                None if listing_expired(deps.storage, &token_id, &env.block.time)? => None,
+                None => listed.may_load(deps.storage, &token_id)?,
+            };
+            if listed_only && listed_price.is_none() {
+                continue;
+            }
+            if let Some(price) = &listed_price {
+                let below_min = filters.min_price.is_some_and(|min| price.amount < min);
+                let above_max = filters.max_price.is_some_and(|max| price.amount > max);
+                if below_min || above_max {
+                    continue;
+                }
+            }
+            seats.push(SeatInfo {
+                token_id,
+                listed_price,
+                listing_expires_at,
+                owner: info.owner,
+                approvals: info.approvals,
+                token_uri: info.token_uri,
+                active: is_active(&info.extension, &env.block.time),
+                extension: info.extension,
+                auction,
+            });
+        }
+
+        // resume after the last seat gone through, even if it did not match
+        let next_start_after = match exhausted {
+            true => None,
+            false => last_scanned,
+        };
+        Ok(AllSeatsResponse {
+            seats,
+            next_start_after,
+        })
     }
 }
+
+fn load_auction(storage: &dyn Storage, token_id: &str) -> Result<Auction, ContractError> {
+    AUCTIONS
+        .may_load(storage, token_id)?
+        .ok_or_else(|| ContractError::AuctionNotFound {
+            token_id: token_id.to_string(),
+        })
+}
+
+fn load_open_auction(
+    storage: &dyn Storage,
+    env: &Env,
+    token_id: &str,
+) -> Result<Auction, ContractError> {
+    let auction = load_auction(storage, token_id)?;
+    if !auction.is_open(env.block.time) {
+        return Err(ContractError::AuctionNotOpen {
+            token_id: token_id.to_string(),
+        });
+    }
+    Ok(auction)
+}
+
+/// Refund of the highest bid of an auction ended without a sale, if any
+fn refund_highest_bid(auction: Auction) -> Option<BankMsg> {
+    auction.highest_bid.map(|bid| BankMsg::Send {
+        to_address: bid.bidder.to_string(),
+        amount: vec![Coin {
+            denom: auction.denom,
+            amount: bid.amount,
+        }],
+    })
+}
+
+fn refund_offer(action: &str, offer: Offer) -> Response<Binary> {
+    Response::new()
+        .add_attribute("action", action)
+        .add_attribute("offer_id", offer.id.to_string())
+        .add_message(BankMsg::Send {
+            to_address: offer.buyer.to_string(),
+            amount: vec![offer.price],
+        })
+}
+
+fn ensure_not_auctioned(storage: &dyn Storage, token_id: &str) -> Result<(), ContractError> {
+    if AUCTIONS.has(storage, token_id) {
+        return Err(ContractError::SeatInAuction {
+            token_id: token_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Merges two iterators of token ids sorted in ascending order
+fn merge_token_ids<'a>(
+    a: impl Iterator<Item = StdResult<String>> + 'a,
+    b: impl Iterator<Item = StdResult<String>> + 'a,
+) -> impl Iterator<Item = StdResult<String>> + 'a {
+    let (mut a, mut b) = (a.peekable(), b.peekable());
+    std::iter::from_fn(move || {
+        let ordering = match (a.peek(), b.peek()) {
+            (Some(Ok(a)), Some(Ok(b))) => a.cmp(b),
+            (Some(_), _) => Ordering::Less,
+            (None, _) => Ordering::Greater,
+        };
+        match ordering {
+            Ordering::Less => a.next(),
+            Ordering::Greater => b.next(),
+            Ordering::Equal => {
+                b.next();
+                a.next()
+            }
+        }
+    })
+}
+
+/// Counts a seat about to be minted against the supply of its tier, if any
+fn record_tier_mint(
+    storage: &mut dyn Storage,
+    extension: &TokenMetadata,
+) -> Result<(), ContractError> {
+    match &extension.tier {
+        Some(tier_id) => tiers::record_mint(storage, tier_id),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn exclusive_start<'a, K>(start_after: &Option<String>) -> Option<Bound<'a, K>> {
+    start_after
+        .as_ref()
+        .map(|token_id| Bound::ExclusiveRaw(token_id.as_bytes().to_vec()))
+}
+
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -0,0 +1,147 @@
+use cosmwasm_std::{
+    Addr, Api, Coin, Empty, Order, StdError, StdResult, Storage, Timestamp, Uint128,
+};
+use cw_storage_plus::{Bound, Item, Map, PrimaryKey};
+use serde::{Deserialize, Serialize};
+
+use crate::msg::SubscriptionConfigMsg;
+use crate::state::{DEFAULT_LIMIT, MAX_LIMIT, PAYMENT_DENOMS};
+use crate::tiers;
+use crate::ContractError;
+
+/// Price and billing period of seat subscriptions
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct SubscriptionConfig {
+    /// Price of a single billing period
+    pub price: Coin,
+    /// Seconds in a billing period
+    pub period: u64,
+    /// Seconds a seat stays active once its paid period ended
+    pub grace_period: u64,
+    /// Receives the subscription payments
+    pub recipient: Addr,
+    /// Tier of the seats minted for new subscriptions
+    pub tier: Option<String>,
+}
+
+/// Seats can only be subscribed to while it is set
+pub const SUBSCRIPTION_CONFIG: Item<SubscriptionConfig> = Item::new("subscription_config");
+
+/// End of the paid period of every subscription, by token id
+pub const SUBSCRIPTIONS: Map<&str, Timestamp> = Map::new("subscriptions");
+
+/// Subscriptions by the end of their paid period in seconds, then token id
+pub const SUBSCRIPTION_EXPIRIES: Map<(u64, &str), Empty> = Map::new("subscription_expiries");
+
+impl SubscriptionConfig {
+    pub fn new(
+        storage: &dyn Storage,
+        api: &dyn Api,
+        msg: SubscriptionConfigMsg,
+    ) -> Result<Self, ContractError> {
+        if msg.period == 0 || msg.price.amount.is_zero() {
+            return Err(ContractError::InvalidSubscription {
+                reason: "price and period cannot be zero".to_string(),
+            });
+        }
+        if !PAYMENT_DENOMS.load(storage)?.contains(&msg.price.denom) {
+            return Err(ContractError::InvalidSubscription {
+                reason: format!("{} is not a payment denom", msg.price.denom),
+            });
+        }
+        if let Some(tier_id) = &msg.tier {
+            tiers::load_tier(storage, tier_id)?;
+        }
+        Ok(SubscriptionConfig {
+            price: msg.price,
+            period: msg.period,
+            grace_period: msg.grace_period,
+            recipient: api.addr_validate(&msg.recipient)?,
+            tier: msg.tier,
+        })
+    }
+
+    /// Price of `periods` billing periods
+    pub fn price_of(&self, periods: u32) -> Result<Coin, ContractError> {
+        if periods == 0 {
+            return Err(ContractError::InvalidSubscription {
+                reason: "at least one period must be paid".to_string(),
+            });
+        }
+        let amount = self
+            .price
+            .amount
+            .checked_mul(Uint128::from(periods))
+            .map_err(StdError::from)?;
+        Ok(Coin {
+            denom: self.price.denom.clone(),
+            amount,
+        })
+    }
+
+    /// End of the paid period once `periods` are paid at `now`. Subscriptions
+    /// still in their grace period resume from the end of their paid period,
+    /// new and lapsed ones start from `now`.
+    pub fn paid_until(
+        &self,
+        paid_until: Option<Timestamp>,
+        now: Timestamp,
+        periods: u32,
+    ) -> Timestamp {
+        let start = match paid_until {
+            Some(paid_until) if self.expires_at(paid_until) >= now => paid_until,
+            _ => now,
+        };
+        start.plus_seconds(self.period.saturating_mul(u64::from(periods)))
+    }
+
+    /// Seats stay active until the grace period after their paid period
+    pub fn expires_at(&self, paid_until: Timestamp) -> Timestamp {
+        paid_until.plus_seconds(self.grace_period)
+    }
+}
+
+/// Records that the subscription `token_id` is paid until `paid_until`
+pub fn save_subscription(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    paid_until: Timestamp,
+) -> StdResult<()> {
+    remove_subscription(storage, token_id)?;
+    SUBSCRIPTIONS.save(storage, token_id, &paid_until)?;
+    SUBSCRIPTION_EXPIRIES.save(storage, (paid_until.seconds(), token_id), &Empty {})
+}
+
+/// Forgets the subscription `token_id`, if any
+pub fn remove_subscription(storage: &mut dyn Storage, token_id: &str) -> StdResult<()> {
+    if let Some(paid_until) = SUBSCRIPTIONS.may_load(storage, token_id)? {
+        SUBSCRIPTIONS.remove(storage, token_id);
+        SUBSCRIPTION_EXPIRIES.remove(storage, (paid_until.seconds(), token_id));
+    }
+    Ok(())
+}
+
+/// Token ids and ends of paid period of the subscriptions whose paid period
+/// ends between `from` and `to` included, ordered by end of paid period
+pub fn expiring_subscriptions(
+    storage: &dyn Storage,
+    from: Timestamp,
+    to: Timestamp,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(String, Timestamp)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let min = match start_after {
+        Some(token_id) => {
+            let paid_until = SUBSCRIPTIONS.load(storage, &token_id)?;
+            Bound::ExclusiveRaw((paid_until.seconds(), token_id.as_str()).joined_key())
+        }
+        None => Bound::inclusive((from.seconds(), "")),
+    };
+    let max = Bound::exclusive((to.seconds().saturating_add(1), ""));
+    SUBSCRIPTION_EXPIRIES
+        .keys(storage, Some(min), Some(max), Order::Ascending)
+        .take(limit)
+        .map(|key| key.map(|(seconds, token_id)| (token_id, Timestamp::from_seconds(seconds))))
+        .collect()
+}
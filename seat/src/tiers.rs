@@ -0,0 +1,135 @@
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+use serde::{Deserialize, Serialize};
+
+use crate::msg::{TierEntry, TierInfo, TiersResponse};
+use crate::state::{exclusive_start, SeatBenefits, DEFAULT_LIMIT, MAX_LIMIT};
+use crate::ContractError;
+
+/// A tier of seats inside the collection, e.g. Gold, Silver or Bronze
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct Tier {
+    pub name: String,
+    pub image_uri: String,
+    pub benefits: Vec<SeatBenefits>,
+    /// Seats of this tier that can ever be minted, unlimited if `None`
+    pub max_supply: Option<u64>,
+    pub minted: u64,
+    /// Overrides the collection `transferable` setting when set
+    pub transferable: Option<bool>,
+}
+
+/// Tiers by tier id
+pub const TIERS: Map<&str, Tier> = Map::new("tiers");
+
+/// Tier of the seats sold in the primary sale, untiered if unset
+pub const SALE_TIER: Item<String> = Item::new("sale_tier");
+
+/// Creates or updates the tier `tier_id`. Updating a tier keeps its minted
+/// count, which `max_supply` cannot go below.
+pub fn set_tier(
+    storage: &mut dyn Storage,
+    tier_id: &str,
+    info: TierInfo,
+) -> Result<Tier, ContractError> {
+    if tier_id.trim().is_empty() || info.name.trim().is_empty() {
+        return Err(ContractError::InvalidTier {
+            reason: "tier id and name cannot be empty".to_string(),
+        });
+    }
+    let minted = TIERS
+        .may_load(storage, tier_id)?
+        .map(|tier| tier.minted)
+        .unwrap_or_default();
+    if let Some(max_supply) = info.max_supply {
+        if max_supply < minted {
+            return Err(ContractError::InvalidTier {
+                reason: format!("{} seats of tier {} were already minted", minted, tier_id),
+            });
+        }
+    }
+    let tier = Tier {
+        name: info.name,
+        image_uri: info.image_uri,
+        benefits: info.benefits,
+        max_supply: info.max_supply,
+        minted,
+        transferable: info.transferable,
+    };
+    TIERS.save(storage, tier_id, &tier)?;
+    Ok(tier)
+}
+
+/// Removes the tier `tier_id`, only possible while none of its seats exist
+pub fn remove_tier(storage: &mut dyn Storage, tier_id: &str) -> Result<(), ContractError> {
+    let tier = load_tier(storage, tier_id)?;
+    if tier.minted > 0 {
+        return Err(ContractError::InvalidTier {
+            reason: format!("seats of tier {} were already minted", tier_id),
+        });
+    }
+    TIERS.remove(storage, tier_id);
+    if SALE_TIER.may_load(storage)?.as_deref() == Some(tier_id) {
+        SALE_TIER.remove(storage);
+    }
+    Ok(())
+}
+
+/// Sets the tier of the seats sold in the primary sale, `None` sells
+/// untiered seats
+pub fn set_sale_tier(
+    storage: &mut dyn Storage,
+    tier_id: Option<String>,
+) -> Result<(), ContractError> {
+    match tier_id {
+        Some(tier_id) => {
+            load_tier(storage, &tier_id)?;
+            SALE_TIER.save(storage, &tier_id)?;
+        }
+        None => SALE_TIER.remove(storage),
+    }
+    Ok(())
+}
+
+pub fn load_tier(storage: &dyn Storage, tier_id: &str) -> Result<Tier, ContractError> {
+    TIERS
+        .may_load(storage, tier_id)?
+        .ok_or_else(|| ContractError::TierNotFound {
+            tier_id: tier_id.to_string(),
+        })
+}
+
+/// Counts a seat of tier `tier_id` about to be minted against its supply
+pub fn record_mint(storage: &mut dyn Storage, tier_id: &str) -> Result<(), ContractError> {
+    let mut tier = load_tier(storage, tier_id)?;
+    if tier
+        .max_supply
+        .is_some_and(|max_supply| tier.minted >= max_supply)
+    {
+        return Err(ContractError::TierSoldOut {
+            tier_id: tier_id.to_string(),
+        });
+    }
+    tier.minted += 1;
+    TIERS.save(storage, tier_id, &tier)?;
+    Ok(())
+}
+
+pub fn query_tiers(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TiersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let tiers = TIERS
+        .range(
+            storage,
+            exclusive_start(&start_after),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(tier_id, tier)| TierEntry { tier_id, tier }))
+        .collect::<StdResult<_>>()?;
+    Ok(TiersResponse { tiers })
+}